@@ -0,0 +1,14 @@
+// This example should be kept in sync with the documentation example in bounded-integer.
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+bounded_integer! {
+    /// Value that can fit in a nibble.
+    #[derive(Debug)]
+    pub enum Nibble: i8 { -8...7 }
+}
+
+fn main() { }
@@ -0,0 +1,63 @@
+use proc_macro2::TokenStream;
+use syn::{Data, DeriveInput, Fields};
+
+use error::Error;
+use eval;
+use repr;
+
+/// Expands `#[derive(BoundedInteger)]`.
+///
+/// Computes the discriminant of every variant, checks that they are contiguous, and invokes
+/// `bounded_integer_impls!` with the variants of the minimum and maximum discriminants.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "bounded integers cannot be generic"));
+    }
+
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => return Err(Error::new_spanned(name, "bounded integers must be enums")),
+    };
+
+    let repr = match repr::find(&input.attrs)? {
+        Some(repr) => repr,
+        None => return Err(Error::new_spanned(name, "expected #[repr(...)] with an integer type")),
+    };
+
+    // (discriminant, variant)
+    let mut discriminants = Vec::new();
+    let mut next = 0;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(&variant.fields, "expected unit variant"));
+        }
+        let discriminant = match variant.discriminant {
+            Some((_, ref expr)) => eval::eval_as(expr, &repr)?,
+            None => next,
+        };
+        discriminants.push((discriminant, &variant.ident));
+        next = discriminant + 1;
+    }
+    discriminants.sort_by_key(|&(discriminant, _)| discriminant);
+
+    let (min_variant, max_variant) = match (discriminants.first(), discriminants.last()) {
+        (Some(&(_, min)), Some(&(_, max))) => (min, max),
+        _ => return Err(Error::new_spanned(name, "expected at least one variant")),
+    };
+    for pair in discriminants.windows(2) {
+        let ((previous, _), (discriminant, variant)) = (pair[0], pair[1]);
+        if discriminant != previous + 1 {
+            let message = format!(
+                "discriminant {} is not contiguous, expected {}",
+                discriminant,
+                previous + 1,
+            );
+            return Err(Error::new_spanned(variant, message));
+        }
+    }
+
+    Ok(quote! {
+        bounded_integer_impls!(#name, #repr, #name::#min_variant, #name::#max_variant);
+    })
+}
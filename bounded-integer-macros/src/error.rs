@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Error spanning from the first to the last erroneous token.
+///
+/// `syn::Error` records the same spans, but only exposes them joined, and spans cannot be joined
+/// on stable Rust, so its errors would only point at the first token.
+#[derive(Debug)]
+pub struct Error {
+    start: Span,
+    end: Span,
+    message: String,
+}
+
+impl Error {
+    /// Creates an error at a span.
+    pub fn new<D: Display>(span: Span, message: D) -> Self {
+        Error { start: span, end: span, message: message.to_string() }
+    }
+
+    /// Creates an error spanning some tokens.
+    pub fn new_spanned<T: ToTokens, D: Display>(tokens: T, message: D) -> Self {
+        let mut spans = tokens.into_token_stream().into_iter().map(|token| token.span());
+        let start = spans.next().unwrap_or_else(Span::call_site);
+        let end = spans.last().unwrap_or(start);
+        Error { start, end, message: message.to_string() }
+    }
+
+    /// Creates a `compile_error!` invocation, with the start span on the macro name and the end
+    /// span on its argument.
+    ///
+    /// The macro is invoked without a path, since `::core` does not resolve in 2015 edition crates
+    /// without `extern crate core`.
+    pub fn to_compile_error(&self) -> TokenStream {
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.start);
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.end);
+        let mut group = Group::new(Delimiter::Brace, TokenTree::from(message).into());
+        group.set_span(self.end);
+
+        let tokens: [TokenTree; 3] = [
+            Ident::new("compile_error", self.start).into(),
+            bang.into(),
+            group.into(),
+        ];
+        tokens.iter().cloned().collect()
+    }
+}
+
+impl From<syn::Error> for Error {
+    fn from(error: syn::Error) -> Self {
+        Error::new(error.span(), error)
+    }
+}
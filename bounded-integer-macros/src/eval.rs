@@ -0,0 +1,170 @@
+use syn::{
+    BinOp,
+    Expr,
+    ExprBinary,
+    ExprGroup,
+    ExprLit,
+    ExprParen,
+    ExprUnary,
+    Ident,
+    Lit,
+    LitInt,
+    UnOp,
+};
+
+use error::Error;
+use repr;
+use tables::REPRS;
+
+/// Integer type with its range.
+type Type = &'static (&'static str, i128, i128);
+
+/// Value of a constant expression, with the type of its suffixed literals.
+#[derive(Clone, Copy)]
+struct Value {
+    int: i128,
+    ty: Option<Type>,
+}
+
+/// Evaluates a constant integer expression, checking for overflow.
+///
+/// Accepts integer literals in any base, with or without suffixes, parentheses, unary `-` and `!`,
+/// and the binary operators `+ - * / % << >> & | ^`. Suffixed literals are collected in
+/// `suffixed` so that their types can be checked against the representation.
+///
+/// Like in Rust, operations on suffixed literals are evaluated at their type, so `255u8 + 1`
+/// overflows and `!0u8` is `255`. Unsuffixed operands take the type of the other operand, and
+/// expressions without suffixes are evaluated as `i128`.
+pub fn eval(expr: &Expr, suffixed: &mut Vec<LitInt>) -> Result<i128, Error> {
+    eval_value(expr, None, suffixed).map(|value| value.int)
+}
+
+/// Evaluates a constant integer expression whose unsuffixed literals have the type `repr`, as
+/// the compiler does for enum discriminants.
+pub fn eval_as(expr: &Expr, repr: &Ident) -> Result<i128, Error> {
+    let ty = REPRS.iter().find(|&&(name, _, _)| repr == name);
+    eval_value(expr, ty, &mut Vec::new()).map(|value| value.int)
+}
+
+/// Evaluates a constant integer expression at the type of its suffixed literals, if any, or at
+/// `default`.
+fn eval_value(
+    expr: &Expr,
+    default: Option<Type>,
+    suffixed: &mut Vec<LitInt>,
+) -> Result<Value, Error> {
+    match *expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. }) => literal(lit, false, default, suffixed),
+        Expr::Paren(ExprParen { ref expr, .. }) => eval_value(expr, default, suffixed),
+        Expr::Group(ExprGroup { ref expr, .. }) => eval_value(expr, default, suffixed),
+        Expr::Unary(ExprUnary { ref op, expr: ref operand, .. }) => {
+            if let (&UnOp::Neg(_), &Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. })) =
+                (op, &**operand)
+            {
+                return literal(lit, true, default, suffixed);
+            }
+            let value = eval_value(operand, default, suffixed)?;
+            let int = match *op {
+                UnOp::Neg(_) => value.int.checked_neg(),
+                UnOp::Not(_) => match value.ty {
+                    // Unsigned types have no sign bit to extend.
+                    Some(&(_, 0, max)) => Some(max - value.int),
+                    _ => Some(!value.int),
+                },
+                _ => return Err(Error::new_spanned(expr, "unsupported operator")),
+            };
+            checked(expr, int, value.ty)
+        },
+        Expr::Binary(ExprBinary { ref left, ref op, ref right, .. }) => {
+            let (left, right) = (
+                eval_value(left, default, suffixed)?,
+                eval_value(right, default, suffixed)?,
+            );
+            let ty = match *op {
+                // The shift amount may be of any type.
+                BinOp::Shl(_) | BinOp::Shr(_) => left.ty,
+                _ => match (left.ty, right.ty) {
+                    (Some(left), Some(right)) if left.0 != right.0 => {
+                        let message = format!("mismatched types `{}` and `{}`", left.0, right.0);
+                        return Err(Error::new_spanned(expr, message));
+                    },
+                    (left, right) => left.or(right),
+                },
+            };
+            let (left, right) = (left.int, right.int);
+            let int = match *op {
+                BinOp::Add(_) => left.checked_add(right),
+                BinOp::Sub(_) => left.checked_sub(right),
+                BinOp::Mul(_) => left.checked_mul(right),
+                BinOp::Div(_) if right == 0 => {
+                    return Err(Error::new_spanned(expr, "division by zero"));
+                },
+                BinOp::Rem(_) if right == 0 => {
+                    return Err(Error::new_spanned(expr, "remainder by zero"));
+                },
+                BinOp::Div(_) => left.checked_div(right),
+                BinOp::Rem(_) => left.checked_rem(right),
+                BinOp::Shl(_) => shift(right, ty).and_then(|shift| {
+                    left.checked_shl(shift).filter(|&result| result >> shift == left)
+                }),
+                BinOp::Shr(_) => shift(right, ty).and_then(|shift| left.checked_shr(shift)),
+                BinOp::BitAnd(_) => Some(left & right),
+                BinOp::BitOr(_) => Some(left | right),
+                BinOp::BitXor(_) => Some(left ^ right),
+                _ => return Err(Error::new_spanned(op, "unsupported operator")),
+            };
+            checked(expr, int, ty)
+        },
+        _ => Err(Error::new_spanned(expr, "expected integer literal or constant expression")),
+    }
+}
+
+/// Evaluates an integer literal, which may be negated, as in `-128i8`.
+///
+/// Unsuffixed literals have the type `default`.
+fn literal(
+    lit: &LitInt,
+    neg: bool,
+    default: Option<Type>,
+    suffixed: &mut Vec<LitInt>,
+) -> Result<Value, Error> {
+    let int = lit.base10_parse::<i128>()?;
+    let int = if neg { -int } else { int };
+    let ty = if lit.suffix().is_empty() {
+        match default {
+            Some(ty) => ty,
+            None => return Ok(Value { int, ty: None }),
+        }
+    } else {
+        match REPRS.iter().find(|&&(name, _, _)| name == lit.suffix()) {
+            Some(ty) => {
+                suffixed.push(lit.clone());
+                ty
+            },
+            None => return Err(repr::unknown(&Ident::new(lit.suffix(), lit.span()))),
+        }
+    };
+    if int < ty.1 || int > ty.2 {
+        return Err(Error::new_spanned(lit, format!("literal out of range for `{}`", ty.0)));
+    }
+    Ok(Value { int, ty: Some(ty) })
+}
+
+/// Checks that the result of an operation did not overflow its type.
+fn checked(expr: &Expr, int: Option<i128>, ty: Option<Type>) -> Result<Value, Error> {
+    match int {
+        Some(int) if ty.is_none_or(|&(_, min, max)| int >= min && int <= max) => {
+            Ok(Value { int, ty })
+        },
+        _ => Err(Error::new_spanned(expr, "overflow in constant expression")),
+    }
+}
+
+/// Converts a shift amount, which must be less than the number of bits in the type.
+fn shift(value: i128, ty: Option<Type>) -> Option<u32> {
+    let bits = match ty {
+        Some(&(_, min, max)) => 128 - max.leading_zeros() + u32::from(min < 0),
+        None => 128,
+    };
+    if (0..i128::from(bits)).contains(&value) { Some(value as u32) } else { None }
+}
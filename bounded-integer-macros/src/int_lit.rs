@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use proc_macro2::{Literal, Span, TokenStream};
+use syn::{Expr, Ident, LitInt};
+
+use error::Error;
+use eval;
+
+/// Signed integer literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntLit {
+    /// Negative.
+    pub neg: bool,
+
+    /// Integer.
+    pub int: u64,
+}
+
+impl IntLit {
+    /// Converts to `i128` without loss.
+    pub fn to_i128(self) -> i128 {
+        if self.neg { -(self.int as i128) } else { self.int as i128 }
+    }
+
+    /// Creates an integer literal expression.
+    pub fn into_expr(self, sp: Span) -> TokenStream {
+        let mut lit = Literal::u64_unsuffixed(self.int);
+        lit.set_span(sp);
+        if self.neg {
+            quote_spanned!(sp=> -#lit)
+        } else {
+            quote_spanned!(sp=> #lit)
+        }
+    }
+
+    /// Creates an ident for an integer literal of the form `...N1, Z0, P1...`.
+    pub fn into_ident(self, sp: Span) -> Ident {
+        let prefix = match (self.neg, self.int) {
+            (true, _) => 'N',
+            (false, 0) => 'Z',
+            (false, _) => 'P',
+        };
+        Ident::new(&format!("{}{}", prefix, self.int), sp)
+    }
+
+    /// Returns the successive integer literal.
+    pub fn succ(self) -> Self {
+        match (self.neg, self.int) {
+            (true, 1) => IntLit { neg: false, int: 0 },
+            (true, i) => IntLit { neg: true, int: i - 1 },
+            (false, i) => IntLit { neg: false, int: i + 1 },
+        }
+    }
+
+    /// Returns the preceding integer literal.
+    pub fn pred(self) -> Self {
+        match (self.neg, self.int) {
+            (false, 0) => IntLit { neg: true, int: 1 },
+            (false, i) => IntLit { neg: false, int: i - 1 },
+            (true, i) => IntLit { neg: true, int: i + 1 },
+        }
+    }
+}
+
+impl IntLit {
+    /// Converts from `i128`, or returns `None` if the magnitude does not fit in `u64`.
+    pub fn from_i128(value: i128) -> Option<Self> {
+        if value > u64::MAX as i128 || value < -(u64::MAX as i128) { return None; }
+        Some(IntLit { neg: value < 0, int: value.unsigned_abs() as u64 })
+    }
+
+    /// Evaluates a constant expression.
+    ///
+    /// Suffixed literals in the expression are added to `suffixed`.
+    pub fn eval(tokens: &TokenStream, suffixed: &mut Vec<LitInt>) -> Result<Self, Error> {
+        let expr: Expr = syn::parse2(tokens.clone())?;
+        let value = eval::eval(&expr, suffixed)?;
+        IntLit::from_i128(value).ok_or_else(|| {
+            Error::new_spanned(tokens, format!("{} does not fit in any representation", value))
+        })
+    }
+}
+
+impl PartialOrd for IntLit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IntLit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (true, true) => other.int.cmp(&self.int),
+            (false, false) => self.int.cmp(&other.int),
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use syn::parse::{Parse, ParseStream};
+use syn::token::Brace;
+use syn::{Attribute, Ident, RangeLimits, Visibility};
+
+use error::Error;
+use int_lit::IntLit;
+use repr;
+use tables::{self, FROM_TYPES};
+
+/// Parsed bounded integer enum.
+#[derive(Debug)]
+pub struct IntegerEnum {
+    /// Attributes.
+    pub attrs: Vec<Attribute>,
+
+    /// Visibility.
+    pub vis: Visibility,
+
+    /// Name.
+    pub name: Ident,
+
+    /// Representation, given or inferred from the range.
+    pub repr: Ident,
+
+    /// Minimum value.
+    pub min: IntLit,
+
+    /// Span of the minimum value.
+    pub min_span: Span,
+
+    /// Maximum value, inclusive.
+    pub max: IntLit,
+}
+
+/// Bounded integer enum as written, before its bounds are evaluated.
+struct Input {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    repr: Option<Ident>,
+    min: TokenStream,
+    min_span: Span,
+    limits: RangeLimits,
+    max: TokenStream,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // $(#[$attr:meta])*
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        // $(pub)? enum
+        let vis = input.parse()?;
+        let _: Token![enum] = input.parse()?;
+
+        // $name:ident $(: $repr:ident)?
+        let name = input.parse()?;
+        let repr = if input.peek(Token![:]) {
+            let _: Token![:] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        // { $min:expr $(...|..=|..) $max:expr }
+        let content;
+        let _: Brace = braced!(content in input);
+
+        let min_span = content.span();
+        let min = parse_bound(&content)?;
+
+        let limits = if content.peek(Token![...]) {
+            let dots: Token![...] = content.parse()?;
+            RangeLimits::Closed(Token![..=](dots.spans))
+        } else {
+            content.parse()?
+        };
+
+        let max = parse_bound(&content)?;
+
+        if !content.is_empty() {
+            return Err(content.error("unexpected token"));
+        }
+
+        Ok(Input { attrs, vis, name, repr, min, min_span, limits, max })
+    }
+}
+
+/// Parses the tokens of a bound, up to the next `.`.
+fn parse_bound(input: ParseStream) -> syn::Result<TokenStream> {
+    let span = input.span();
+    let tokens = input.step(|cursor| {
+        let mut tokens = TokenStream::new();
+        let mut rest = *cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            match tt {
+                TokenTree::Punct(ref punct) if punct.as_char() == '.' => break,
+                _ => tokens.extend(Some(tt)),
+            }
+            rest = next;
+        }
+        Ok((tokens, rest))
+    })?;
+    if tokens.is_empty() {
+        return Err(syn::Error::new(span, "expected integer literal or constant expression"));
+    }
+    Ok(tokens)
+}
+
+impl IntegerEnum {
+    /// Parses a bounded integer enum.
+    ///
+    /// Roughly equivalent to:
+    ///
+    /// ```text
+    /// $(#[$attr:meta])*
+    /// $(pub)? enum $name:ident $(: $repr:ident)? { $min:expr...$max:expr }
+    /// ```
+    ///
+    /// If `$repr` is omitted, the smallest representation which fits the range is used, preferring
+    /// unsigned types, unless a bound contains a suffixed literal. The range may also be written
+    /// `$min..=$max`, or `$min..$max` to exclude `$max`.
+    ///
+    /// The bounds may be constant expressions, which are evaluated by `eval::eval`.
+    pub fn parse(tokens: TokenStream) -> Result<Self, Error> {
+        let Input { attrs, vis, name, mut repr, min, min_span, limits, max } = syn::parse2(tokens)?;
+        let (min_tokens, max_tokens) = (min, max);
+
+        if let Some(ref repr) = repr {
+            if repr::range(repr).is_none() { return Err(repr::unknown(repr)); }
+        }
+
+        let mut suffixed = Vec::new();
+        let min = IntLit::eval(&min_tokens, &mut suffixed)?;
+        let mut max = IntLit::eval(&max_tokens, &mut suffixed)?;
+
+        match limits {
+            RangeLimits::Closed(_) => if max < min {
+                let message =
+                    "maximum must be greater than or equal to minimum in an inclusive range";
+                return Err(Error::new_spanned(max_tokens, message));
+            },
+            RangeLimits::HalfOpen(_) => {
+                if max <= min {
+                    let message = "maximum must be greater than minimum in a half-open range";
+                    return Err(Error::new_spanned(max_tokens, message));
+                }
+                max = max.pred();
+            },
+        }
+
+        // Suffixes, which `eval::eval` has checked, must all agree with `$repr`.
+        for lit in &suffixed {
+            let suffix = Ident::new(lit.suffix(), lit.span());
+            match repr {
+                Some(ref repr) if *repr != suffix => {
+                    let message = format!(
+                        "suffix `{}` does not match representation `{}`",
+                        suffix,
+                        repr,
+                    );
+                    return Err(Error::new_spanned(lit, message));
+                },
+                Some(_) => (),
+                None => repr = Some(suffix),
+            }
+        }
+
+        let smallest = tables::smallest_repr(min.to_i128(), max.to_i128());
+        let repr = match repr {
+            Some(repr) => {
+                let (repr_min, repr_max) = repr::range(&repr).unwrap();
+                for &(lit, ref tokens) in &[(min, &min_tokens), (max, &max_tokens)] {
+                    let value = lit.to_i128();
+                    if value >= repr_min && value <= repr_max { continue; }
+                    let message = match smallest {
+                        Some(smallest) => format!(
+                            "{} does not fit in `{}`, the smallest representation that fits the \
+                             range is `{}`",
+                            value,
+                            repr,
+                            smallest,
+                        ),
+                        None => format!(
+                            "{} does not fit in `{}` or any other representation",
+                            value,
+                            repr,
+                        ),
+                    };
+                    return Err(Error::new_spanned(tokens, message));
+                }
+                repr
+            },
+            None => match smallest {
+                Some(smallest) => Ident::new(smallest, Span::call_site()),
+                None => {
+                    let message = format!(
+                        "{}...{} does not fit in any representation",
+                        min.to_i128(),
+                        max.to_i128(),
+                    );
+                    return Err(Error::new_spanned(quote!(#min_tokens #max_tokens), message));
+                },
+            },
+        };
+
+        Ok(IntegerEnum { attrs, vis, name, repr, min, min_span, max })
+    }
+
+    /// Creates an enum item and `bounded_integer_impls` and `bounded_integer_from_impls` macro
+    /// invocation items.
+    ///
+    /// - Adds `#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]`
+    /// - Adds `#[repr($repr)]`
+    /// - Generates variants of the form `...N1, Z0, P1...`.
+    /// - Sets item visibility.
+    /// - Invokes `bounded_integer_impls!` macro.
+    /// - Invokes `bounded_integer_from_impls!` macro for the primitive types which fit the range.
+    pub fn into_tokens(self) -> TokenStream {
+        let IntegerEnum { ref attrs, ref vis, ref name, ref repr, .. } = self;
+        let variants = self.variants();
+        let first = self.min.into_ident(self.min_span);
+        let last = self.max.into_ident(self.min_span);
+        let conversions_macro = self.conversions_macro();
+
+        quote! {
+            #(#attrs)*
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            #[repr(#repr)]
+            #vis enum #name { #(#variants),* }
+            bounded_integer_impls!(#name, #repr, #name::#first, #name::#last);
+            #conversions_macro
+        }
+    }
+
+    /// Generates variants for the range of the form `N1 = -1, Z0 = 0, P1 = 1`.
+    fn variants(&self) -> Vec<TokenStream> {
+        let mut vec = Vec::new();
+        let mut current = self.min;
+        loop {
+            let ident = current.into_ident(self.min_span);
+            // Literals spanned at the minimum would not match the source text of the minimum.
+            let expr = current.into_expr(Span::call_site());
+            vec.push(quote!(#ident = #expr));
+
+            if current == self.max { break; }
+            current = current.succ();
+        }
+        vec
+    }
+
+    /// Creates a `bounded_integer_from_impls` macro invocation for every primitive type other
+    /// than `$repr` which represents the range exactly, or nothing if there are none.
+    fn conversions_macro(&self) -> TokenStream {
+        let (min, max) = (self.min.to_i128(), self.max.to_i128());
+        let types = FROM_TYPES.iter()
+            .filter(|&&(ty, ty_min, ty_max)| self.repr != ty && min >= ty_min && max <= ty_max)
+            .map(|&(ty, _, _)| Ident::new(ty, Span::call_site()))
+            .collect::<Vec<_>>();
+        if types.is_empty() { return TokenStream::new(); }
+
+        let name = &self.name;
+        quote!(bounded_integer_from_impls!(#name, #(#types),*);)
+    }
+}
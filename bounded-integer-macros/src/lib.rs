@@ -0,0 +1,270 @@
+//! Provides procedural macros for the [`bounded-integer`][main] crate on stable Rust.
+//!
+//! [main]: https://cmcenroe.me/bounded-integer/bounded_integer
+//!
+//! # Syntax
+//!
+//! The syntax parsed by `bounded_integer!` is the same as that of the
+//! [`bounded-integer-plugin`][plugin] crate, and is roughly equivalent to the following
+//! `macro_rules!`-like matcher.
+//!
+//! [plugin]: https://cmcenroe.me/bounded-integer/bounded_integer_plugin
+//!
+//! ```ignore
+//! $(#[$attr:meta])*
+//! $(pub)? enum $name:ident $(: $repr:ident)? { $min:expr...$max:expr }
+//! ```
+//!
+//! Which expands to the following:
+//!
+//! ```ignore
+//! $(#[$attr:meta])*
+//! #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! #[repr($repr)]
+//! $(pub)? enum $name { ... }
+//! bounded_integer_impls!($name, $repr, ..., ...);
+//! bounded_integer_from_impls!($name, ...);
+//! ```
+//!
+//! Where `...` are variants of the form `...N1, Z0, P1...`, and the primitive types other than
+//! `$repr` which can represent the whole range.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! {
+//!     /// Value that can fit in a nibble.
+//!     #[derive(Debug)]
+//!     pub enum Nibble: i8 { -8...7 }
+//! }
+//! # fn main() {
+//! assert_eq!(Nibble::P3, Nibble::P1 + Nibble::P2);
+//! # }
+//! ```
+//!
+//! Both bounds must fit in `$repr`, which must be an integer type valid in `#[repr(...)]`.
+//! Otherwise the error suggests the smallest representation that fits.
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A: u8 { -1...300 } }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A: u9 { 0...300 } }
+//! # fn main() { }
+//! ```
+//!
+//! If `$repr` is omitted, the smallest representation which fits the range is used, preferring
+//! unsigned types, unless a bound contains a suffixed literal such as `255u16`. The range may also
+//! be written `$min..=$max`, or `$min..$max` to exclude `$max`.
+//!
+//! The bounds may be integer literals in any base, or constant expressions using parentheses,
+//! unary `-` and `!`, and the binary operators `+ - * / % << >> & | ^`. Expressions are evaluated
+//! at expansion time, and overflow is an error. As in Rust, operations on suffixed literals are
+//! evaluated at their type, so `!0u8` is `255` and `255u8 + 1` overflows, while expressions
+//! without suffixes are evaluated as `i128`, so `!0` is `-1`. Suffixed literals must match `$repr`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! use bounded_integer::Bounded;
+//!
+//! bounded_integer! { enum Percent { 0..=100 } }
+//! bounded_integer! { enum Register { 0x00..1 << 5 } }
+//! # fn main() {
+//! let repr: u8 = Percent::P50.to_repr();
+//! assert_eq!(50, repr);
+//! assert_eq!(31, Register::MAX_REPR);
+//! # }
+//! ```
+//!
+//! Overflow, division by zero and shifts by at least the number of bits in the type fail to
+//! compile, as do suffixed literals which do not match `$repr` or are out of range for their type.
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A { 0...255u8 + 1 } }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A { 0...1 / 0 } }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A { 0...1 >> 128 } }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A: u8 { 0...255u16 } }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! bounded_integer! { enum A { 0...256u8 } }
+//! # fn main() { }
+//! ```
+//!
+//! # Derive
+//!
+//! `#[derive(BoundedInteger)]` implements all bounded integer traits for a C-like enum, like
+//! `bounded_integer_impls!`, but reads the representation from `#[repr(...)]` and the minimum and
+//! maximum from the discriminants, so they cannot go out of sync with the enum. The discriminants
+//! may be constant expressions like the bounds of `bounded_integer!`, evaluated at the
+//! representation like the compiler does, and must be contiguous.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! /// Value that can fit in a nibble.
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+//! #[repr(i8)]
+//! pub enum Nibble {
+//!     N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+//! }
+//! # fn main() {
+//! assert_eq!(Nibble::P3, Nibble::P1 + Nibble::P2);
+//! # }
+//! ```
+//!
+//! Gaps between discriminants fail to compile, as does a missing `#[repr(...)]`.
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+//! #[repr(u8)]
+//! enum Gap { P1 = 1, P2, P4 = 4 }
+//! # fn main() { }
+//! ```
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+//! enum NoRepr { Z0, P1 }
+//! # fn main() { }
+//! ```
+//!
+//! Representations which change the layout, such as `align` and `packed`, are not supported.
+//!
+//! ```compile_fail
+//! #[macro_use]
+//! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+//! #[repr(u8, align(2))]
+//! enum Aligned { Z0, P1 }
+//! # fn main() { }
+//! ```
+//!
+//! Note that the `bounded_integer_impls!` and `bounded_integer_from_impls!` macros are provided
+//! by the [`bounded-integer`][main] crate, which must be imported with `#[macro_use]`.
+
+#![warn(
+    missing_docs,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results,
+    variant_size_differences,
+)]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+mod derive;
+mod error;
+mod eval;
+mod int_lit;
+mod integer_enum;
+mod repr;
+#[allow(clippy::redundant_static_lifetimes, clippy::unnecessary_map_or)]
+mod tables;
+
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+
+use error::Error;
+use integer_enum::IntegerEnum;
+
+/// Generates an enum with variants for a range, and implements the bounded integer traits for it.
+#[proc_macro]
+pub fn bounded_integer(input: TokenStream) -> TokenStream {
+    IntegerEnum::parse(input.into())
+        .map(IntegerEnum::into_tokens)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives the bounded integer traits for a C-like enum with contiguous variants.
+#[proc_macro_derive(BoundedInteger)]
+pub fn derive_bounded_integer(input: TokenStream) -> TokenStream {
+    syn::parse::<DeriveInput>(input)
+        .map_err(Error::from)
+        .and_then(|input| derive::expand(&input))
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
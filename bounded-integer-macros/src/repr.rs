@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+use syn::{token, Attribute, Ident};
+
+use error::Error;
+use tables;
+
+/// Returns an error for a representation which is not valid.
+pub fn unknown(repr: &Ident) -> Error {
+    let message = format!(
+        "unknown representation `{}`, expected one of {}",
+        repr,
+        tables::repr_names(),
+    );
+    Error::new(repr.span(), message)
+}
+
+/// Returns the range of a representation, or `None` if it is not valid.
+pub fn range(repr: &Ident) -> Option<(i128, i128)> {
+    tables::repr_range(&repr.to_string())
+}
+
+/// Finds the integer type in a `#[repr(...)]` attribute.
+pub fn find(attrs: &[Attribute]) -> syn::Result<Option<Ident>> {
+    let mut repr = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("align") || meta.path.is_ident("packed") {
+                let message = format!(
+                    "unsupported representation `{}`, bounded integers must have the layout of \
+                     their integer type",
+                    meta.path.get_ident().unwrap(),
+                );
+                return Err(meta.error(message));
+            }
+            if let Some(ident) = meta.path.get_ident() {
+                if range(ident).is_some() {
+                    repr = Some(ident.clone());
+                }
+            }
+            // Skips the arguments of other representations.
+            if meta.input.peek(token::Paren) {
+                let content;
+                let _ = parenthesized!(content in meta.input);
+                let _: TokenStream = content.parse()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
@@ -0,0 +1,59 @@
+// Shared with `bounded-integer-plugin`, which includes this file.
+
+/// Primitive types which `From` may be implemented for, with the ranges they represent exactly.
+pub const FROM_TYPES: &'static [(&'static str, i128, i128)] = &[
+    ("u8", 0, u8::MAX as i128),
+    ("u16", 0, u16::MAX as i128),
+    ("u32", 0, u32::MAX as i128),
+    ("u64", 0, u64::MAX as i128),
+    ("u128", 0, i128::MAX),
+    ("i8", i8::MIN as i128, i8::MAX as i128),
+    ("i16", i16::MIN as i128, i16::MAX as i128),
+    ("i32", i32::MIN as i128, i32::MAX as i128),
+    ("i64", i64::MIN as i128, i64::MAX as i128),
+    ("i128", i128::MIN, i128::MAX),
+    ("f32", -(1 << 24), 1 << 24),
+    ("f64", -(1 << 53), 1 << 53),
+];
+
+/// Integer types valid as a representation, with their ranges.
+pub const REPRS: &'static [(&'static str, i128, i128)] = &[
+    ("u8", u8::MIN as i128, u8::MAX as i128),
+    ("u16", u16::MIN as i128, u16::MAX as i128),
+    ("u32", u32::MIN as i128, u32::MAX as i128),
+    ("u64", u64::MIN as i128, u64::MAX as i128),
+    ("usize", usize::MIN as i128, usize::MAX as i128),
+    ("i8", i8::MIN as i128, i8::MAX as i128),
+    ("i16", i16::MIN as i128, i16::MAX as i128),
+    ("i32", i32::MIN as i128, i32::MAX as i128),
+    ("i64", i64::MIN as i128, i64::MAX as i128),
+    ("isize", isize::MIN as i128, isize::MAX as i128),
+];
+
+/// Representations in order of preference for a range, smallest first and unsigned before signed.
+pub const SMALLEST: &'static [&'static str] = &[
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64",
+];
+
+/// Returns the names of the valid representations, separated by commas.
+pub fn repr_names() -> String {
+    REPRS.iter().map(|&(name, _, _)| name).collect::<Vec<_>>().join(", ")
+}
+
+/// Returns the range of a representation, or `None` if it is not valid.
+pub fn repr_range(repr: &str) -> Option<(i128, i128)> {
+    REPRS.iter()
+        .find(|&&(name, _, _)| name == repr)
+        .map(|&(_, min, max)| (min, max))
+}
+
+/// Returns the smallest representation which fits `min...max`, or `None` if none does.
+pub fn smallest_repr(min: i128, max: i128) -> Option<&'static str> {
+    SMALLEST.iter()
+        .cloned()
+        .find(|&name| {
+            repr_range(name).map_or(false, |(repr_min, repr_max)| {
+                min >= repr_min && max <= repr_max
+            })
+        })
+}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+trait AssertImplCopy: Copy { }
+trait AssertImplEq: Eq { }
+trait AssertImplOrd: Ord { }
+
+bounded_integer! { enum A: u8 { 0...1 } }
+impl AssertImplCopy for A { }
+impl AssertImplEq for A { }
+impl AssertImplOrd for A { }
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+include!("fixtures/const_expr.rs");
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+trait AssertDefined { }
+
+bounded_integer! { enum A: u8 { 0...1 } }
+impl AssertDefined for A { }
+
+mod b {
+    bounded_integer! { pub enum B: u8 { 0...1 } }
+}
+impl AssertDefined for b::B { }
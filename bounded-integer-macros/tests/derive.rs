@@ -0,0 +1,79 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+use bounded_integer::{Bounded, BoundedInteger};
+
+trait AssertImplBoundedInteger: BoundedInteger { }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(u8)]
+enum A { Z0, P1, P2, P3 }
+impl AssertImplBoundedInteger for A { }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(i8)]
+enum B { N2 = -2, N1, Z0, P1, P2 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(u16)]
+enum C { P1001 = 1001, P1000 = 1000, P1002 = 1002 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(i64)]
+enum D { P7 = 7 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(i8)]
+enum E { N2 = -1 - 1, N1, Z0 = 1 >> 1 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BoundedInteger)]
+#[repr(u8)]
+enum F { P254 = !0 - 1, P255 }
+
+#[test]
+fn implicit_discriminants() {
+    assert_eq!(A::Z0, A::MIN);
+    assert_eq!(A::P3, A::MAX);
+    assert_eq!(Some(A::P2), A::from_repr(2));
+    assert_eq!(None, A::from_repr(4));
+}
+
+#[test]
+fn negative_discriminants() {
+    assert_eq!(-2, B::MIN_REPR);
+    assert_eq!(2, B::MAX_REPR);
+    assert_eq!(Some(B::N1), B::from_repr(-1));
+}
+
+#[test]
+fn unordered_discriminants() {
+    assert_eq!(C::P1000, C::MIN);
+    assert_eq!(C::P1002, C::MAX);
+    assert_eq!(Some(C::P1001), C::from_repr(1001));
+}
+
+#[test]
+fn single_variant() {
+    assert_eq!(D::P7, D::MIN);
+    assert_eq!(D::P7, D::MAX);
+    assert_eq!(1, D::COUNT);
+}
+
+#[test]
+fn expression_discriminants() {
+    assert_eq!(E::N2, E::MIN);
+    assert_eq!(E::Z0, E::MAX);
+    assert_eq!(254, F::MIN_REPR);
+    assert_eq!(255, F::MAX_REPR);
+}
+
+#[test]
+fn operators() {
+    assert_eq!(A::P3, A::P1 + A::P2);
+    assert_eq!(B::N2, -B::P2);
+    assert_eq!(3u8, A::P3.into());
+}
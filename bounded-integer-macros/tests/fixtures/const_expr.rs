@@ -0,0 +1,53 @@
+// Shared with the `bounded-integer-plugin` tests, which include this file.
+
+use bounded_integer::{Bounded, BoundedInteger, Repr};
+
+trait AssertBoundedIntegerRepr<R: Repr>: BoundedInteger<Repr = R> { }
+
+bounded_integer! { enum A { 0x0...0xF } }
+bounded_integer! { enum B: i8 { -0o10...0b111 } }
+bounded_integer! { enum C { 1_0...1_2 } }
+bounded_integer! { enum D { 0u16...3u16 } }
+bounded_integer! { enum E: i8 { -128i8...-127 } }
+bounded_integer! { enum F { 0...(1 << 4) - 1 } }
+bounded_integer! { enum G { -(2 * 8)..0 } }
+bounded_integer! { enum H { 0xFFFF_FFFE...!0 & 0xFFFF_FFFF } }
+bounded_integer! { enum I { 0...!0u8 } }
+bounded_integer! { enum J { !0i8...1i8 << 6 } }
+
+impl AssertBoundedIntegerRepr<u8> for A { }
+impl AssertBoundedIntegerRepr<u16> for D { }
+impl AssertBoundedIntegerRepr<u8> for F { }
+impl AssertBoundedIntegerRepr<i8> for G { }
+impl AssertBoundedIntegerRepr<u32> for H { }
+impl AssertBoundedIntegerRepr<u8> for I { }
+impl AssertBoundedIntegerRepr<i8> for J { }
+
+#[test]
+fn literals() {
+    assert_eq!(15, A::MAX_REPR);
+    assert_eq!(-8, B::MIN_REPR);
+    assert_eq!(7, B::MAX_REPR);
+    assert_eq!(10, C::MIN_REPR);
+    assert_eq!(12, C::MAX_REPR);
+}
+
+#[test]
+fn suffixes() {
+    assert_eq!(3, D::MAX_REPR);
+    assert_eq!(-128, E::MIN_REPR);
+}
+
+#[test]
+fn suffixed_arithmetic() {
+    assert_eq!(255, I::MAX_REPR);
+    assert_eq!(-1, J::MIN_REPR);
+    assert_eq!(64, J::MAX_REPR);
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(15, F::MAX_REPR);
+    assert_eq!(-16, G::MIN_REPR);
+    assert_eq!(-1, G::MAX_REPR);
+}
@@ -0,0 +1,34 @@
+// Shared with the `bounded-integer-plugin` tests, which include this file.
+
+use bounded_integer::{BoundedInteger, Repr};
+
+trait AssertBoundedIntegerRepr<R: Repr>: BoundedInteger<Repr = R> { }
+
+bounded_integer! { enum A { 0...1 } }
+bounded_integer! { enum B { -1...1 } }
+bounded_integer! { enum C { 254...255 } }
+bounded_integer! { enum D { 255...256 } }
+bounded_integer! { enum E { -129...-128 } }
+bounded_integer! { enum F { 65535...65536 } }
+bounded_integer! { enum G { 2147483647...2147483648 } }
+bounded_integer! { enum H { -2147483649...-2147483648 } }
+bounded_integer! { enum I { 18446744073709551615...18446744073709551615 } }
+bounded_integer! { pub enum J { -1...0 } }
+
+impl AssertBoundedIntegerRepr<u8> for A { }
+impl AssertBoundedIntegerRepr<i8> for B { }
+impl AssertBoundedIntegerRepr<u8> for C { }
+impl AssertBoundedIntegerRepr<u16> for D { }
+impl AssertBoundedIntegerRepr<i16> for E { }
+impl AssertBoundedIntegerRepr<u32> for F { }
+impl AssertBoundedIntegerRepr<u32> for G { }
+impl AssertBoundedIntegerRepr<i64> for H { }
+impl AssertBoundedIntegerRepr<u64> for I { }
+impl AssertBoundedIntegerRepr<i8> for J { }
+
+#[test]
+fn size_of() {
+    assert_eq!(1, std::mem::size_of::<A>());
+    assert_eq!(2, std::mem::size_of::<D>());
+    assert_eq!(8, std::mem::size_of::<H>());
+}
@@ -0,0 +1,55 @@
+// Shared with the `bounded-integer-plugin` tests, which include this file.
+
+use bounded_integer::Bounded;
+
+bounded_integer! { enum A: u8 { 0...255 } }
+bounded_integer! { enum B: i8 { -128...127 } }
+bounded_integer! { enum C: u64 { 18446744073709551614...18446744073709551615 } }
+bounded_integer! { enum D: i64 { -9223372036854775808...-9223372036854775807 } }
+bounded_integer! { enum E: usize { 0...1 } }
+
+bounded_integer! {
+    #[derive(Debug)]
+    enum F: i8 { -2..=2 }
+}
+bounded_integer! {
+    #[derive(Debug)]
+    enum G { 0..4 }
+}
+bounded_integer! {
+    #[derive(Debug)]
+    enum H { -3..0 }
+}
+bounded_integer! { enum I { 0..256 } }
+
+#[test]
+fn full_range() {
+    assert_eq!(0, A::MIN_REPR);
+    assert_eq!(255, A::MAX_REPR);
+    assert_eq!(-128, B::MIN_REPR);
+    assert_eq!(127, B::MAX_REPR);
+}
+
+#[test]
+fn extreme_range() {
+    assert_eq!(u64::MAX, C::MAX_REPR);
+    assert_eq!(i64::MIN, D::MIN_REPR);
+}
+
+#[test]
+fn inclusive() {
+    assert_eq!(F::N2, F::MIN);
+    assert_eq!(F::P2, F::MAX);
+    assert_eq!(5, F::COUNT);
+}
+
+#[test]
+fn half_open() {
+    assert_eq!(G::Z0, G::MIN);
+    assert_eq!(G::P3, G::MAX);
+    assert_eq!(4, G::COUNT);
+    assert_eq!(H::N3, H::MIN);
+    assert_eq!(H::N1, H::MAX);
+    assert_eq!(3, H::COUNT);
+    assert_eq!(255u8, I::MAX_REPR);
+}
@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bounded_integer;
+#[macro_use]
+extern crate bounded_integer_macros;
+
+use std::fmt::Debug;
+
+trait AssertImplDebug: Debug { }
+
+bounded_integer! {
+    /// Documentation.
+    #[derive(Debug)]
+    enum A: u8 { 0...1 }
+}
+impl AssertImplDebug for A { }
@@ -0,0 +1,161 @@
+use syntax::ast::{BinOpKind, Expr, ExprKind, LitIntType, LitKind, UnOp};
+use syntax::codemap::Span;
+
+use integer_enum::unknown_repr;
+use tables::REPRS;
+
+/// Suffixed integer literal.
+#[derive(Debug, Clone, Copy)]
+pub struct Suffixed {
+    /// Span of the literal.
+    pub span: Span,
+
+    /// Suffix.
+    pub suffix: &'static str,
+}
+
+/// Integer type with its range.
+type Type = &'static (&'static str, i128, i128);
+
+/// Value of a constant expression, with the type of its suffixed literals.
+#[derive(Clone, Copy)]
+struct Value {
+    int: i128,
+    ty: Option<Type>,
+}
+
+/// Evaluates a constant integer expression, checking for overflow.
+///
+/// Accepts integer literals in any base, with or without suffixes, parentheses, unary `-` and `!`,
+/// and the binary operators `+ - * / % << >> & | ^`. Suffixed literals are collected in
+/// `suffixed` so that their types can be checked against the representation.
+///
+/// Like in Rust, operations on suffixed literals are evaluated at their type, so `255u8 + 1`
+/// overflows and `!0u8` is `255`. Unsuffixed operands take the type of the other operand, and
+/// expressions without suffixes are evaluated as `i128`.
+pub fn eval(expr: &Expr, suffixed: &mut Vec<Suffixed>) -> Result<i128, (Span, String)> {
+    eval_value(expr, suffixed).map(|value| value.int)
+}
+
+/// Evaluates a constant integer expression at the type of its suffixed literals, if any.
+fn eval_value(expr: &Expr, suffixed: &mut Vec<Suffixed>) -> Result<Value, (Span, String)> {
+    match expr.node {
+        ExprKind::Lit(_) => literal(expr, false, suffixed),
+        ExprKind::Paren(ref expr) => eval_value(&*expr, suffixed),
+        ExprKind::Unary(op, ref operand) => {
+            if let (UnOp::Neg, &ExprKind::Lit(_)) = (op, &operand.node) {
+                return literal(&*operand, true, suffixed);
+            }
+            let value = try!(eval_value(&*operand, suffixed));
+            let int = match op {
+                UnOp::Neg => value.int.checked_neg(),
+                UnOp::Not => match value.ty {
+                    // Unsigned types have no sign bit to extend.
+                    Some(&(_, 0, max)) => Some(max - value.int),
+                    _ => Some(!value.int),
+                },
+                _ => return Err((expr.span, "unsupported operator".to_owned())),
+            };
+            checked(expr, int, value.ty)
+        },
+        ExprKind::Binary(op, ref left, ref right) => {
+            let left = try!(eval_value(&*left, suffixed));
+            let right = try!(eval_value(&*right, suffixed));
+            let ty = match op.node {
+                // The shift amount may be of any type.
+                BinOpKind::Shl | BinOpKind::Shr => left.ty,
+                _ => match (left.ty, right.ty) {
+                    (Some(left), Some(right)) if left.0 != right.0 => {
+                        let msg = format!("mismatched types `{}` and `{}`", left.0, right.0);
+                        return Err((expr.span, msg));
+                    },
+                    (left, right) => left.or(right),
+                },
+            };
+            let (left, right) = (left.int, right.int);
+            let int = match op.node {
+                BinOpKind::Add => left.checked_add(right),
+                BinOpKind::Sub => left.checked_sub(right),
+                BinOpKind::Mul => left.checked_mul(right),
+                BinOpKind::Div if right == 0 => {
+                    return Err((expr.span, "division by zero".to_owned()));
+                },
+                BinOpKind::Rem if right == 0 => {
+                    return Err((expr.span, "remainder by zero".to_owned()));
+                },
+                BinOpKind::Div => left.checked_div(right),
+                BinOpKind::Rem => left.checked_rem(right),
+                BinOpKind::Shl => shift(right, ty).and_then(|shift| {
+                    left.checked_shl(shift).and_then(|result| {
+                        if result >> shift == left { Some(result) } else { None }
+                    })
+                }),
+                BinOpKind::Shr => shift(right, ty).and_then(|shift| left.checked_shr(shift)),
+                BinOpKind::BitAnd => Some(left & right),
+                BinOpKind::BitOr => Some(left | right),
+                BinOpKind::BitXor => Some(left ^ right),
+                _ => return Err((op.span, "unsupported operator".to_owned())),
+            };
+            checked(expr, int, ty)
+        },
+        _ => Err((expr.span, "expected integer literal or constant expression".to_owned())),
+    }
+}
+
+/// Evaluates an integer literal expression, which may be negated, as in `-128i8`.
+fn literal(
+    expr: &Expr,
+    neg: bool,
+    suffixed: &mut Vec<Suffixed>,
+) -> Result<Value, (Span, String)> {
+    let (int, ty) = match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Int(int, ty) => (int, ty),
+            _ => {
+                let msg = "expected integer literal or constant expression".to_owned();
+                return Err((expr.span, msg));
+            },
+        },
+        _ => unreachable!(),
+    };
+    let int = if neg { -(int as i128) } else { int as i128 };
+    let suffix = match ty {
+        LitIntType::Signed(ty) => ty.ty_to_string(),
+        LitIntType::Unsigned(ty) => ty.ty_to_string(),
+        LitIntType::Unsuffixed => return Ok(Value { int: int, ty: None }),
+    };
+
+    let ty = match REPRS.iter().find(|&&(name, _, _)| name == suffix) {
+        Some(ty) => ty,
+        None => return Err((expr.span, unknown_repr(suffix))),
+    };
+    if int < ty.1 || int > ty.2 {
+        return Err((expr.span, format!("literal out of range for `{}`", ty.0)));
+    }
+    suffixed.push(Suffixed { span: expr.span, suffix: suffix });
+    Ok(Value { int: int, ty: Some(ty) })
+}
+
+/// Checks that the result of an operation did not overflow its type.
+fn checked(expr: &Expr, int: Option<i128>, ty: Option<Type>) -> Result<Value, (Span, String)> {
+    match int {
+        Some(int) => match ty {
+            Some(&(_, min, max)) if int < min || int > max => (),
+            _ => return Ok(Value { int: int, ty: ty }),
+        },
+        None => (),
+    }
+    Err((expr.span, "overflow in constant expression".to_owned()))
+}
+
+/// Converts a shift amount, which must be less than the number of bits in the type.
+fn shift(value: i128, ty: Option<Type>) -> Option<u32> {
+    let bits = match ty {
+        Some(&(_, min, max)) => {
+            let bits = 128 - max.leading_zeros();
+            if min < 0 { bits + 1 } else { bits }
+        },
+        None => 128,
+    };
+    if value >= 0 && value < bits as i128 { Some(value as u32) } else { None }
+}
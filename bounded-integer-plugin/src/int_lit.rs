@@ -60,6 +60,17 @@ impl IntLit {
         cx.ident_of(&format!("{}{}", prefix, self.int))
     }
 
+    /// Converts from `i128`, or returns `None` if the magnitude does not fit in `u64`.
+    pub fn from_i128(value: i128) -> Option<Self> {
+        if value > u64::MAX as i128 || value < -(u64::MAX as i128) { return None; }
+        Some(IntLit { neg: value < 0, int: if value < 0 { -value } else { value } as u64 })
+    }
+
+    /// Converts to `i128` without loss.
+    pub fn to_i128(self) -> i128 {
+        if self.neg { -(self.int as i128) } else { self.int as i128 }
+    }
+
     /// Returns the successive integer literal.
     pub fn succ(self) -> Self {
         match (self.neg, self.int) {
@@ -68,6 +79,15 @@ impl IntLit {
             (false, i) => IntLit { neg: false, int: i + 1 },
         }
     }
+
+    /// Returns the preceding integer literal.
+    pub fn pred(self) -> Self {
+        match (self.neg, self.int) {
+            (false, 0) => IntLit { neg: true, int: 1 },
+            (false, i) => IntLit { neg: false, int: i - 1 },
+            (true, i) => IntLit { neg: true, int: i + 1 },
+        }
+    }
 }
 
 impl PartialOrd for IntLit {
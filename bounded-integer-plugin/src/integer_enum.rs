@@ -7,6 +7,7 @@ use syntax::ast::{
     Item,
     ItemKind,
     Mac_,
+    RangeLimits,
     TokenTree,
     Variant,
     Visibility,
@@ -16,11 +17,42 @@ use syntax::errors::DiagnosticBuilder;
 use syntax::ext::base::ExtCtxt;
 use syntax::ext::build::AstBuilder;
 use syntax::parse::token::{DelimToken, InternedString, Token};
+use syntax::parse::parser::{LhsExpr, Parser};
 use syntax::parse::token::keywords;
 use syntax::ptr::P;
+use syntax::util::parser::AssocOp;
 
+use eval::{self, Suffixed};
+use tables::{FROM_TYPES, repr_names, repr_range, smallest_repr};
 use IntLit;
 
+/// Returns the error message for a representation which is not valid.
+pub fn unknown_repr(repr: &str) -> String {
+    format!("unknown representation `{}`, expected one of {}", repr, repr_names())
+}
+
+/// Parses and evaluates a bound, returning it as an integer literal expression.
+fn parse_bound<'a>(
+    parser: &mut Parser<'a>,
+    cx: &ExtCtxt,
+    suffixed: &mut Vec<Suffixed>,
+) -> Result<(P<Expr>, IntLit), DiagnosticBuilder<'a>> {
+    // Stops before `..` and `...`, which have a lower precedence than any constant operator.
+    let min_prec = AssocOp::DotDot.precedence() + 1;
+    let expr = try!(parser.parse_assoc_expr_with(min_prec, LhsExpr::NotYetParsed));
+    let value = match eval::eval(&*expr, suffixed) {
+        Ok(value) => value,
+        Err((span, msg)) => return Err(parser.span_fatal(span, &msg)),
+    };
+    match IntLit::from_i128(value) {
+        Some(lit) => Ok((lit.into_expr(cx, expr.span), lit)),
+        None => {
+            let msg = format!("{} does not fit in any representation", value);
+            Err(parser.span_fatal(expr.span, &msg))
+        },
+    }
+}
+
 /// Parsed bounded integer enum.
 #[derive(Debug)]
 pub struct IntegerEnum {
@@ -33,13 +65,13 @@ pub struct IntegerEnum {
     /// Name.
     pub name: Ident,
 
-    /// Representation.
+    /// Representation, given or inferred from the range.
     pub repr: Ident,
 
-    /// Minimum value.
+    /// Minimum value, as an integer literal expression.
     pub min: P<Expr>,
 
-    /// Maximum value.
+    /// Maximum value, inclusive, as an integer literal expression.
     pub max: P<Expr>,
 }
 
@@ -50,8 +82,14 @@ impl IntegerEnum {
     ///
     /// ```text
     /// $(#[$attr:meta])*
-    /// $(pub)? enum $name:ident: $repr:ident { $min:expr...$max:expr }
+    /// $(pub)? enum $name:ident $(: $repr:ident)? { $min:expr...$max:expr }
     /// ```
+    ///
+    /// If `$repr` is omitted, the smallest representation which fits the range is used, preferring
+    /// unsigned types, unless a bound contains a suffixed literal. The range may also be written
+    /// `$min..=$max`, or `$min..$max` to exclude `$max`.
+    ///
+    /// The bounds may be constant expressions, which are evaluated by `eval::eval`.
     pub fn parse_tts<'a>(
         cx: &'a ExtCtxt,
         tts: &[TokenTree],
@@ -65,38 +103,109 @@ impl IntegerEnum {
         let is_pub = parser.eat_keyword(keywords::Pub);
         try!(parser.expect_keyword(keywords::Enum));
 
-        // $name:ident: $repr:ident
+        // $name:ident $(: $repr:ident)?
         let name = try!(parser.parse_ident());
-        try!(parser.expect(&Token::Colon));
-        let repr = try!(parser.parse_ident());
+        let mut repr = if parser.eat(&Token::Colon) {
+            let repr_span = parser.span;
+            let repr = try!(parser.parse_ident());
+            if repr_range(&*repr.name.as_str()).is_none() {
+                return Err(parser.span_fatal(repr_span, &unknown_repr(&*repr.name.as_str())));
+            }
+            Some(repr)
+        } else {
+            None
+        };
 
-        // { $min:expr...$max:expr }
+        // { $min:expr $(...|..=|..) $max:expr }
         try!(parser.expect(&Token::OpenDelim(DelimToken::Brace)));
 
-        let min = try!(parser.parse_pat_literal_maybe_minus());
-        let min_lit = match IntLit::from_expr(&*min) {
-            Ok(l) => l,
-            Err(_) => return Err(parser.span_fatal(min.span, "expected integer literal")),
-        };
-
-        try!(parser.expect(&Token::DotDotDot));
+        let mut suffixed = Vec::new();
+        let (min, min_lit) = try!(parse_bound(&mut parser, cx, &mut suffixed));
 
-        let max = try!(parser.parse_pat_literal_maybe_minus());
-        let max_lit = match IntLit::from_expr(&*max) {
-            Ok(l) => l,
-            Err(_) => return Err(parser.span_fatal(max.span, "expected integer literal")),
+        // `..=` is lexed as `..` followed by `=`.
+        let limits = if parser.eat(&Token::DotDotDot) {
+            RangeLimits::Closed
+        } else {
+            try!(parser.expect(&Token::DotDot));
+            if parser.eat(&Token::Eq) { RangeLimits::Closed } else { RangeLimits::HalfOpen }
         };
 
+        let (mut max, mut max_lit) = try!(parse_bound(&mut parser, cx, &mut suffixed));
+
         try!(parser.expect(&Token::CloseDelim(DelimToken::Brace)));
 
-        if max_lit < min_lit {
-            return Err(
-                parser.span_fatal(max.span, "maximum must be greater than or equal to minimum")
-            );
+        match limits {
+            RangeLimits::Closed => if max_lit < min_lit {
+                let msg = "maximum must be greater than or equal to minimum in an inclusive range";
+                return Err(parser.span_fatal(max.span, msg));
+            },
+            RangeLimits::HalfOpen => {
+                if max_lit <= min_lit {
+                    let msg = "maximum must be greater than minimum in a half-open range";
+                    return Err(parser.span_fatal(max.span, msg));
+                }
+                max_lit = max_lit.pred();
+                max = max_lit.into_expr(cx, max.span);
+            },
         }
 
         try!(parser.expect(&Token::Eof));
 
+        // Suffixes, which `eval::eval` has checked, must all agree with `$repr`.
+        for lit in &suffixed {
+            match repr {
+                Some(repr) if &*repr.name.as_str() != lit.suffix => {
+                    let msg = format!(
+                        "suffix `{}` does not match representation `{}`",
+                        lit.suffix,
+                        repr,
+                    );
+                    return Err(parser.span_fatal(lit.span, &msg));
+                },
+                Some(_) => (),
+                None => repr = Some(cx.ident_of(lit.suffix)),
+            }
+        }
+
+        let smallest = smallest_repr(min_lit.to_i128(), max_lit.to_i128());
+        let repr = match repr {
+            Some(repr) => {
+                let (repr_min, repr_max) = repr_range(&*repr.name.as_str()).unwrap();
+                for &(lit, ref expr) in &[(min_lit, &min), (max_lit, &max)] {
+                    let value = lit.to_i128();
+                    if value >= repr_min && value <= repr_max { continue; }
+                    let msg = match smallest {
+                        Some(smallest) => format!(
+                            "{} does not fit in `{}`, the smallest representation that fits the \
+                             range is `{}`",
+                            value,
+                            repr,
+                            smallest,
+                        ),
+                        None => format!(
+                            "{} does not fit in `{}` or any other representation",
+                            value,
+                            repr,
+                        ),
+                    };
+                    return Err(parser.span_fatal(expr.span, &msg));
+                }
+                repr
+            },
+            None => match smallest {
+                Some(smallest) => cx.ident_of(smallest),
+                None => {
+                    let msg = format!(
+                        "{}...{} does not fit in any representation",
+                        min_lit.to_i128(),
+                        max_lit.to_i128(),
+                    );
+                    let span = codemap::mk_sp(min.span.lo, max.span.hi);
+                    return Err(parser.span_fatal(span, &msg));
+                },
+            },
+        };
+
         Ok(IntegerEnum {
             attrs: attrs,
             is_pub: is_pub,
@@ -107,19 +216,22 @@ impl IntegerEnum {
         })
     }
 
-    /// Creates an enum item and a `bounded_integer_impls` macro invocation item.
+    /// Creates an enum item and `bounded_integer_impls` and `bounded_integer_from_impls` macro
+    /// invocation items.
     ///
     /// - Adds `#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]`
     /// - Adds `#[repr($repr)]`
     /// - Generates variants of the form `...N1, Z0, P1...`.
     /// - Sets item visibility.
     /// - Invokes `bounded_integer_impls!` macro.
+    /// - Invokes `bounded_integer_from_impls!` macro for the primitive types which fit the range.
     pub fn into_items(mut self, cx: &ExtCtxt, sp: Span) -> Vec<P<Item>> {
         self.add_derives(cx, sp);
         self.add_repr(cx, sp);
 
         let variants = self.variants(cx);
         let impls_macro_item = self.impls_macro_item(&variants, cx, sp);
+        let from_macro_item = self.from_macro_item(cx, sp);
 
         let enum_def = EnumDef { variants: variants };
         let enum_kind = ItemKind::Enum(enum_def, Default::default());
@@ -129,7 +241,9 @@ impl IntegerEnum {
             item
         });
 
-        vec![enum_item, impls_macro_item]
+        let mut items = vec![enum_item, impls_macro_item];
+        items.extend(from_macro_item);
+        items
     }
 
     /// Adds `#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]` to the attributes.
@@ -196,4 +310,28 @@ impl IntegerEnum {
         });
         cx.item(sp, keywords::Invalid.ident(), vec![], ItemKind::Mac(mac))
     }
+
+    /// Creates a `bounded_integer_from_impls` macro invocation item for every primitive type other
+    /// than `$repr` which represents the range exactly, or `None` if there are none.
+    fn from_macro_item(&self, cx: &ExtCtxt, sp: Span) -> Option<P<Item>> {
+        let min = IntLit::from_expr(&*self.min).unwrap().to_i128();
+        let max = IntLit::from_expr(&*self.max).unwrap().to_i128();
+        let repr = self.repr.name.as_str();
+
+        let mut tts = vec![TokenTree::Token(sp, Token::Ident(self.name))];
+        for &(ty, ty_min, ty_max) in FROM_TYPES {
+            if ty == &*repr || min < ty_min || max > ty_max { continue; }
+            tts.push(TokenTree::Token(sp, Token::Comma));
+            tts.push(TokenTree::Token(sp, Token::Ident(cx.ident_of(ty))));
+        }
+        if tts.len() == 1 { return None; }
+
+        let path = cx.path_ident(sp, cx.ident_of("bounded_integer_from_impls"));
+        let mac = codemap::respan(sp, Mac_ {
+            path: path,
+            tts: tts,
+            ctxt: ast::EMPTY_CTXT,
+        });
+        Some(cx.item(sp, keywords::Invalid.ident(), vec![], ItemKind::Mac(mac)))
+    }
 }
@@ -1,7 +1,16 @@
 //! Provides the procedural macro `bounded_integer!` for generating enums for the
 //! [`bounded-integer`][main] crate.
 //!
+//! This crate is a compiler plugin, which only builds on nightly Rust. The
+//! [`bounded-integer-macros`][macros] crate provides the same macro on stable Rust.
+//!
+//! **This crate no longer builds on any toolchain.** Its bounds are evaluated as `i128` and use the
+//! associated constants of the integer types, such as `u8::MAX`, which the nightlies that still
+//! provide the `syntax` and `rustc_plugin` crates do not have, and later nightlies removed compiler
+//! plugins. It is kept for reference; use [`bounded-integer-macros`][macros] instead.
+//!
 //! [main]: https://cmcenroe.me/bounded-integer/bounded_integer
+//! [macros]: https://cmcenroe.me/bounded-integer/bounded_integer_macros
 //!
 //! # Syntax
 //!
@@ -10,7 +19,7 @@
 //!
 //! ```ignore
 //! $(#[$attr:meta])*
-//! $(pub)? enum $name:ident: $repr:ident { $min:expr...$max:expr }
+//! $(pub)? enum $name:ident $(: $repr:ident)? { $min:expr...$max:expr }
 //! ```
 //!
 //! Which expands to the following:
@@ -21,12 +30,27 @@
 //! #[repr($repr)]
 //! $(pub)? enum $name { ... }
 //! bounded_integer_impls!($name, $repr, ..., ...);
+//! bounded_integer_from_impls!($name, ...);
 //! ```
 //!
-//! Where `...` are variants of the form `...N1, Z0, P1...`.
+//! Where `...` are variants of the form `...N1, Z0, P1...`, and the primitive types other than
+//! `$repr` which can represent the whole range.
+//!
+//! Both bounds must fit in `$repr`, which must be an integer type valid in `#[repr(...)]`.
+//! Otherwise the error suggests the smallest representation that fits. If `$repr` is omitted, the
+//! smallest representation which fits the range is used, preferring unsigned types, unless a bound
+//! contains a suffixed literal such as `255u16`.
+//!
+//! The range may also be written `$min..=$max`, or `$min..$max` to exclude `$max`.
 //!
-//! Note that the `bounded_integer_impls!` macro is provided by the [`bounded-integer`][main]
-//! crate.
+//! The bounds may be integer literals in any base, or constant expressions using parentheses,
+//! unary `-` and `!`, and the binary operators `+ - * / % << >> & | ^`. Expressions are evaluated
+//! at expansion time, and overflow is an error. As in Rust, operations on suffixed literals are
+//! evaluated at their type, so `!0u8` is `255` and `255u8 + 1` overflows, while expressions
+//! without suffixes are evaluated as `i128`, so `!0` is `-1`. Suffixed literals must match `$repr`.
+//!
+//! Note that the `bounded_integer_impls!` and `bounded_integer_from_impls!` macros are provided
+//! by the [`bounded-integer`][main] crate.
 
 #![feature(plugin_registrar, rustc_private)]
 
@@ -52,6 +76,13 @@ mod integer_enum;
 pub use int_lit::IntLit;
 mod int_lit;
 
+mod eval;
+
+/// Tables shared with `bounded-integer-macros`.
+mod tables {
+    include!("../../bounded-integer-macros/src/tables.rs");
+}
+
 use rustc_plugin::Registry;
 use syntax::ast::TokenTree;
 use syntax::codemap::Span;
@@ -6,7 +6,8 @@ extern crate bounded_integer;
 
 use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
 
-use bounded_integer::{BoundedInteger, Repr};
+use bounded_integer::Repr;
+use bounded_integer::prelude::*;
 
 trait AssertImplBoundedInteger: BoundedInteger { }
 trait AssertBoundedIntegerRepr<R: Repr>: BoundedInteger<Repr = R> { }
@@ -27,6 +28,9 @@ impl AssertImplBoundedInteger for A { }
 impl AssertBoundedIntegerRepr<i8> for A { }
 
 impl AssertImplInto<i8> for A { }
+impl AssertImplInto<i16> for A { }
+impl AssertImplInto<i128> for A { }
+impl AssertImplInto<f32> for A { }
 
 impl AssertImplAdd<A> for A { }
 impl<'a> AssertImplAdd<&'a A> for A { }
@@ -100,3 +104,18 @@ fn min_value() {
 fn max_value() {
     assert_eq!(A::P5, A::max_value());
 }
+
+#[test]
+fn from() {
+    assert_eq!(-3i64, i64::from(A::N3));
+    assert_eq!(5.0f64, f64::from(A::P5));
+}
+
+#[test]
+fn consts() {
+    assert_eq!(A::N5, A::MIN);
+    assert_eq!(A::P5, A::MAX);
+    assert_eq!(-5, A::MIN_REPR);
+    assert_eq!(5, A::MAX_REPR);
+    assert_eq!(11, A::COUNT);
+}
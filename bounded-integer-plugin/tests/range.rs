@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+#![feature(plugin)]
+#![plugin(bounded_integer_plugin)]
+
+#[macro_use]
+extern crate bounded_integer;
+
+include!("../../bounded-integer-macros/tests/fixtures/range.rs");
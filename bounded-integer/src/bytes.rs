@@ -0,0 +1,97 @@
+#[cfg(feature = "std")]
+use std::io;
+
+use Bounded;
+use RangeError;
+use Repr;
+
+/// Byte encoding.
+///
+/// Values are encoded as `Self::Repr`, and decoded values outside the range of `Self` are
+/// rejected with [`RangeError`](struct.RangeError.html).
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait ByteEncoding: Bounded {
+    /// Returns the big-endian bytes of `Self::Repr`.
+    fn to_be_bytes(self) -> <Self::Repr as Repr>::Bytes {
+        self.to_repr().to_be_bytes()
+    }
+
+    /// Returns the little-endian bytes of `Self::Repr`.
+    fn to_le_bytes(self) -> <Self::Repr as Repr>::Bytes {
+        self.to_repr().to_le_bytes()
+    }
+
+    /// Returns the native-endian bytes of `Self::Repr`.
+    fn to_ne_bytes(self) -> <Self::Repr as Repr>::Bytes {
+        self.to_repr().to_ne_bytes()
+    }
+
+    /// Converts from big-endian bytes of `Self::Repr`.
+    fn from_be_bytes(bytes: <Self::Repr as Repr>::Bytes) -> Result<Self, RangeError> {
+        from_repr(Self::Repr::from_be_bytes(bytes))
+    }
+
+    /// Converts from little-endian bytes of `Self::Repr`.
+    fn from_le_bytes(bytes: <Self::Repr as Repr>::Bytes) -> Result<Self, RangeError> {
+        from_repr(Self::Repr::from_le_bytes(bytes))
+    }
+
+    /// Converts from native-endian bytes of `Self::Repr`.
+    fn from_ne_bytes(bytes: <Self::Repr as Repr>::Bytes) -> Result<Self, RangeError> {
+        from_repr(Self::Repr::from_ne_bytes(bytes))
+    }
+
+    /// Reads big-endian bytes of `Self::Repr`.
+    ///
+    /// Out of range values fail with `io::ErrorKind::InvalidData`.
+    #[cfg(feature = "std")]
+    fn read_be_from<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let bytes = read_bytes::<Self, R>(reader)?;
+        Self::from_be_bytes(bytes).map_err(invalid_data)
+    }
+
+    /// Reads little-endian bytes of `Self::Repr`.
+    ///
+    /// Out of range values fail with `io::ErrorKind::InvalidData`.
+    #[cfg(feature = "std")]
+    fn read_le_from<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let bytes = read_bytes::<Self, R>(reader)?;
+        Self::from_le_bytes(bytes).map_err(invalid_data)
+    }
+
+    /// Writes big-endian bytes of `Self::Repr`.
+    #[cfg(feature = "std")]
+    fn write_be_to<W: io::Write + ?Sized>(self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.to_be_bytes().as_ref())
+    }
+
+    /// Writes little-endian bytes of `Self::Repr`.
+    #[cfg(feature = "std")]
+    fn write_le_to<W: io::Write + ?Sized>(self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.to_le_bytes().as_ref())
+    }
+}
+
+impl<T: Bounded> ByteEncoding for T { }
+
+/// Converts from `T::Repr` to `T`, failing with `RangeError`.
+fn from_repr<T: Bounded>(repr: T::Repr) -> Result<T, RangeError> {
+    T::from_repr(repr).ok_or_else(RangeError::new::<T>)
+}
+
+/// Reads exactly the bytes of `T::Repr`.
+#[cfg(feature = "std")]
+fn read_bytes<T: Bounded, R: io::Read + ?Sized>(
+    reader: &mut R,
+) -> io::Result<<T::Repr as Repr>::Bytes> {
+    let mut bytes = <T::Repr as Repr>::Bytes::default();
+    reader.read_exact(bytes.as_mut())?;
+    Ok(bytes)
+}
+
+/// Converts a `RangeError` to an `io::Error`.
+#[cfg(feature = "std")]
+fn invalid_data(error: RangeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
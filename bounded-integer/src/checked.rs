@@ -0,0 +1,217 @@
+use core::ops;
+
+use ArithmeticError;
+use Bounded;
+use Operation;
+use Repr;
+
+/// Checked arithmetic.
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait CheckedArith: Bounded {
+    /// Checked integer addition.
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_add_repr(other.to_repr())
+    }
+
+    /// Checked integer subtraction.
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_sub_repr(other.to_repr())
+    }
+
+    /// Checked integer multiplication.
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul_repr(other.to_repr())
+    }
+
+    /// Checked integer division.
+    fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_div_repr(other.to_repr())
+    }
+
+    /// Checked integer remainder.
+    fn checked_rem(self, other: Self) -> Option<Self> {
+        self.checked_rem_repr(other.to_repr())
+    }
+
+    /// Checked integer negation.
+    fn checked_neg(self) -> Option<Self> {
+        self.to_repr().checked_neg().and_then(Self::from_repr)
+    }
+
+    // Checked arithmetic with `Self::Repr`.
+
+    /// Checked integer addition with `Self::Repr`.
+    fn checked_add_repr(self, other: Self::Repr) -> Option<Self> {
+        self.to_repr().checked_add(other).and_then(Self::from_repr)
+    }
+
+    /// Checked integer subtraction with `Self::Repr`.
+    fn checked_sub_repr(self, other: Self::Repr) -> Option<Self> {
+        self.to_repr().checked_sub(other).and_then(Self::from_repr)
+    }
+
+    /// Checked integer multiplication with `Self::Repr`.
+    fn checked_mul_repr(self, other: Self::Repr) -> Option<Self> {
+        self.to_repr().checked_mul(other).and_then(Self::from_repr)
+    }
+
+    /// Checked integer division with `Self::Repr`.
+    fn checked_div_repr(self, other: Self::Repr) -> Option<Self> {
+        self.to_repr().checked_div(other).and_then(Self::from_repr)
+    }
+
+    /// Checked integer remainder with `Self::Repr`.
+    fn checked_rem_repr(self, other: Self::Repr) -> Option<Self> {
+        self.to_repr().checked_rem(other).and_then(Self::from_repr)
+    }
+
+    // Fallible arithmetic.
+
+    /// Fallible integer addition.
+    fn try_add(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.try_add_repr(other.to_repr())
+    }
+
+    /// Fallible integer subtraction.
+    fn try_sub(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.try_sub_repr(other.to_repr())
+    }
+
+    /// Fallible integer multiplication.
+    fn try_mul(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.try_mul_repr(other.to_repr())
+    }
+
+    /// Fallible integer division.
+    fn try_div(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.try_div_repr(other.to_repr())
+    }
+
+    /// Fallible integer remainder.
+    fn try_rem(self, other: Self) -> Result<Self, ArithmeticError> {
+        self.try_rem_repr(other.to_repr())
+    }
+
+    /// Fallible integer negation.
+    fn try_neg(self) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Neg, self.to_repr(), None)
+    }
+
+    // Fallible arithmetic with `Self::Repr`.
+
+    /// Fallible integer addition with `Self::Repr`.
+    fn try_add_repr(self, other: Self::Repr) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Add, self.to_repr(), Some(other))
+    }
+
+    /// Fallible integer subtraction with `Self::Repr`.
+    fn try_sub_repr(self, other: Self::Repr) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Sub, self.to_repr(), Some(other))
+    }
+
+    /// Fallible integer multiplication with `Self::Repr`.
+    fn try_mul_repr(self, other: Self::Repr) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Mul, self.to_repr(), Some(other))
+    }
+
+    /// Fallible integer division with `Self::Repr`.
+    fn try_div_repr(self, other: Self::Repr) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Div, self.to_repr(), Some(other))
+    }
+
+    /// Fallible integer remainder with `Self::Repr`.
+    fn try_rem_repr(self, other: Self::Repr) -> Result<Self, ArithmeticError> {
+        try_arith(Operation::Rem, self.to_repr(), Some(other))
+    }
+}
+
+impl<T: Bounded> CheckedArith for T { }
+
+/// Performs an operation in `i128` and converts the result back to `T`.
+fn try_arith<T: Bounded>(
+    operation: Operation,
+    lhs: T::Repr,
+    rhs: Option<T::Repr>,
+) -> Result<T, ArithmeticError> {
+    let lhs = lhs.to_i128();
+    let rhs = rhs.map(Repr::to_i128);
+    let result = match (operation, rhs) {
+        (Operation::Div, Some(0)) | (Operation::Rem, Some(0)) => {
+            return Err(ArithmeticError::division_by_zero(operation, lhs));
+        },
+        (Operation::Add, Some(rhs)) => lhs.checked_add(rhs),
+        (Operation::Sub, Some(rhs)) => lhs.checked_sub(rhs),
+        (Operation::Mul, Some(rhs)) => lhs.checked_mul(rhs),
+        (Operation::Div, Some(rhs)) => lhs.checked_div(rhs),
+        (Operation::Rem, Some(rhs)) => lhs.checked_rem(rhs),
+        (Operation::Neg, None) => lhs.checked_neg(),
+        _ => unreachable!(),
+    };
+
+    let (min, max) = (T::MIN_REPR.to_i128(), T::MAX_REPR.to_i128());
+    match result {
+        Some(r) if r >= min && r <= max => {
+            if let Some(value) = T::Repr::from_i128(r).and_then(T::from_repr) {
+                return Ok(value);
+            }
+        },
+        _ => (),
+    }
+
+    // Only the product of two large operands can overflow `i128`.
+    let positive = (lhs < 0) == (rhs.unwrap_or(0) < 0);
+    Err(ArithmeticError::out_of_range(operation, lhs, rhs, result, positive, min, max))
+}
+
+/// Provides intentionally-checked arithmetic on `T`.
+///
+/// Operators on `Checked<T>` use the checked arithmetic of
+/// [`CheckedArith`](trait.CheckedArith.html). An operation which overflows produces
+/// `Checked(None)`, which propagates through any further operations.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bounded_integer;
+/// # use bounded_integer::Checked;
+/// # fn main() {
+/// let x = Checked(Some(100u8));
+/// assert_eq!(Checked(Some(200)), x + x);
+/// assert_eq!(Checked(None), x + x + x - x);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<T>(pub Option<T>);
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self { Checked(Some(value)) }
+}
+
+macro_rules! checked_binop_impl {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $checked_fn:ident) => {
+        impl<T: Bounded> ops::$op for Checked<T> {
+            type Output = Self;
+            fn $op_fn(self, rhs: Self) -> Self {
+                match (self.0, rhs.0) {
+                    (Some(lhs), Some(rhs)) => Checked(lhs.$checked_fn(rhs)),
+                    _ => Checked(None),
+                }
+            }
+        }
+        impl<T: Bounded> ops::$assign for Checked<T> {
+            fn $assign_fn(&mut self, rhs: Self) { *self = ops::$op::$op_fn(*self, rhs); }
+        }
+    }
+}
+
+checked_binop_impl!(Add, add, AddAssign, add_assign, checked_add);
+checked_binop_impl!(Sub, sub, SubAssign, sub_assign, checked_sub);
+checked_binop_impl!(Mul, mul, MulAssign, mul_assign, checked_mul);
+checked_binop_impl!(Div, div, DivAssign, div_assign, checked_div);
+checked_binop_impl!(Rem, rem, RemAssign, rem_assign, checked_rem);
+
+impl<T: Bounded> ops::Neg for Checked<T> {
+    type Output = Self;
+    fn neg(self) -> Self { Checked(self.0.and_then(T::checked_neg)) }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "std")]
+use core::mem::ManuallyDrop;
+use core::slice;
+
+use Bounded;
+
+/// Bounded integers whose values are exactly the contiguous range of `Self::Repr` from
+/// `Self::MIN_REPR` to `Self::MAX_REPR`, with the same layout as `Self::Repr`.
+///
+/// Implemented by [`bounded_integer_impl!`](macro.bounded_integer_impl.html), for the primitive
+/// integer types, and for `bool`.
+///
+/// # Safety
+///
+/// `Self` must have the same size and alignment as `Self::Repr`, and transmuting any
+/// `Self::Repr` value in `Self::MIN_REPR...Self::MAX_REPR` must produce a valid `Self` equal to
+/// the value returned by `Self::from_repr`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// use bounded_integer::Contiguous;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+/// # fn main() {
+/// let samples = [3, 0, 2];
+/// assert_eq!(Ok(&[TwoBit::P3, TwoBit::Z0, TwoBit::P2][..]), TwoBit::from_repr_slice(&samples));
+/// assert_eq!(Err(1), TwoBit::from_repr_slice(&[3, 4, 5]));
+/// # }
+/// ```
+pub unsafe trait Contiguous: Bounded {
+    /// Reinterprets a slice of `Self::Repr` as a slice of `Self` without copying.
+    ///
+    /// Returns the index of the first value out of range on failure.
+    fn from_repr_slice(slice: &[Self::Repr]) -> Result<&[Self], usize> {
+        validate::<Self>(slice)?;
+        Ok(unsafe { slice::from_raw_parts(slice.as_ptr() as *const Self, slice.len()) })
+    }
+
+    /// Reinterprets a mutable slice of `Self::Repr` as a mutable slice of `Self` without copying.
+    ///
+    /// Returns the index of the first value out of range on failure.
+    fn from_repr_slice_mut(slice: &mut [Self::Repr]) -> Result<&mut [Self], usize> {
+        validate::<Self>(slice)?;
+        Ok(unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self, slice.len()) })
+    }
+
+    /// Reinterprets a slice of `Self` as a slice of `Self::Repr` without copying.
+    fn as_repr_slice(slice: &[Self]) -> &[Self::Repr] {
+        unsafe { slice::from_raw_parts(slice.as_ptr() as *const Self::Repr, slice.len()) }
+    }
+
+    /// Reinterprets a mutable slice of `Self` as a mutable slice of `Self::Repr` without copying.
+    ///
+    /// # Safety
+    ///
+    /// Every value in the slice must be in range when the borrow ends.
+    unsafe fn as_repr_slice_mut(slice: &mut [Self]) -> &mut [Self::Repr] {
+        slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut Self::Repr, slice.len())
+    }
+
+    /// Reinterprets a vector of `Self::Repr` as a vector of `Self` without copying.
+    ///
+    /// Returns the index of the first value out of range on failure.
+    #[cfg(feature = "std")]
+    fn from_repr_vec(vec: Vec<Self::Repr>) -> Result<Vec<Self>, usize> {
+        validate::<Self>(&vec)?;
+        let mut vec = ManuallyDrop::new(vec);
+        let (ptr, len, capacity) = (vec.as_mut_ptr() as *mut Self, vec.len(), vec.capacity());
+        Ok(unsafe { Vec::from_raw_parts(ptr, len, capacity) })
+    }
+
+    /// Reinterprets a vector of `Self` as a vector of `Self::Repr` without copying.
+    #[cfg(feature = "std")]
+    fn into_repr_vec(vec: Vec<Self>) -> Vec<Self::Repr> {
+        let mut vec = ManuallyDrop::new(vec);
+        let (ptr, len, capacity) = (vec.as_mut_ptr() as *mut Self::Repr, vec.len(), vec.capacity());
+        unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+    }
+}
+
+/// Number of values checked at once by `validate` and the functions in `slice`.
+pub(crate) const CHUNK: usize = 64;
+
+/// Checks that every value is in the range of `T`, returning the index of the first which is not.
+///
+/// Each chunk is checked without branching on individual values, so the check can be vectorized,
+/// and only a chunk containing an invalid value is searched.
+fn validate<T: Bounded>(slice: &[T::Repr]) -> Result<(), usize> {
+    let (min, max) = (T::MIN_REPR, T::MAX_REPR);
+    let in_range = |repr: &T::Repr| (*repr >= min) & (*repr <= max);
+    for (i, chunk) in slice.chunks(CHUNK).enumerate() {
+        if !chunk.iter().fold(true, |valid, repr| valid & in_range(repr)) {
+            let position = chunk.iter().position(|repr| !in_range(repr)).unwrap();
+            return Err(i * CHUNK + position);
+        }
+    }
+    Ok(())
+}
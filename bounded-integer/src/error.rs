@@ -0,0 +1,208 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use Bounded;
+use Repr;
+
+/// Arithmetic operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Addition.
+    Add,
+
+    /// Subtraction.
+    Sub,
+
+    /// Multiplication.
+    Mul,
+
+    /// Division.
+    Div,
+
+    /// Remainder.
+    Rem,
+
+    /// Negation.
+    Neg,
+}
+
+impl Operation {
+    /// Returns the operator symbol.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Sub => "-",
+            Operation::Mul => "*",
+            Operation::Div => "/",
+            Operation::Rem => "%",
+            Operation::Neg => "-",
+        }
+    }
+}
+
+/// Bound of a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bound {
+    /// Minimum value.
+    Min(i128),
+
+    /// Maximum value.
+    Max(i128),
+}
+
+/// Error from a failed arithmetic operation.
+///
+/// Returned by the `try_*` methods of [`CheckedArith`](trait.CheckedArith.html). Operands and
+/// results are stored as `i128`, which can represent every `Repr` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArithmeticError {
+    operation: Operation,
+    lhs: i128,
+    rhs: i128,
+    result: Option<i128>,
+    cause: Cause,
+}
+
+/// Cause of an arithmetic error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Cause {
+    /// The result is outside the bound.
+    Bound(Bound),
+
+    /// The result is within the bounds, but in a gap between the values of a sparse type.
+    Gap,
+
+    /// The operation divided by zero.
+    DivisionByZero,
+}
+
+impl ArithmeticError {
+    /// Creates an error for an operation whose result is outside `min...max`, or in a gap between
+    /// values if it is inside.
+    ///
+    /// `result` is the unbounded result if it is representable as `i128`, and `positive` is the
+    /// sign of the unbounded result.
+    pub(crate) fn out_of_range(
+        operation: Operation,
+        lhs: i128,
+        rhs: Option<i128>,
+        result: Option<i128>,
+        positive: bool,
+        min: i128,
+        max: i128,
+    ) -> Self {
+        let cause = match result {
+            Some(r) if r < min => Cause::Bound(Bound::Min(min)),
+            Some(r) if r > max => Cause::Bound(Bound::Max(max)),
+            Some(_) => Cause::Gap,
+            None if positive => Cause::Bound(Bound::Max(max)),
+            None => Cause::Bound(Bound::Min(min)),
+        };
+        ArithmeticError {
+            operation,
+            lhs,
+            rhs: rhs.unwrap_or(0),
+            result,
+            cause,
+        }
+    }
+
+    /// Creates an error for division or remainder by zero.
+    pub(crate) fn division_by_zero(operation: Operation, lhs: i128) -> Self {
+        ArithmeticError {
+            operation,
+            lhs,
+            rhs: 0,
+            result: None,
+            cause: Cause::DivisionByZero,
+        }
+    }
+
+    /// Returns the operation.
+    pub fn operation(&self) -> Operation { self.operation }
+
+    /// Returns the left-hand operand, or the only operand of negation.
+    pub fn lhs(&self) -> i128 { self.lhs }
+
+    /// Returns the right-hand operand, or `None` for negation.
+    pub fn rhs(&self) -> Option<i128> {
+        if self.operation == Operation::Neg { None } else { Some(self.rhs) }
+    }
+
+    /// Returns the unbounded result, or `None` if it is not representable as `i128` or the
+    /// operation divided by zero.
+    pub fn result(&self) -> Option<i128> { self.result }
+
+    /// Returns the bound which the result violated, or `None` if the result is in a gap or the
+    /// operation divided by zero.
+    pub fn bound(&self) -> Option<Bound> {
+        match self.cause {
+            Cause::Bound(bound) => Some(bound),
+            Cause::Gap | Cause::DivisionByZero => None,
+        }
+    }
+
+    /// Returns true if the result is within the bounds, but in a gap between the values of a type
+    /// which is not [`Contiguous`](trait.Contiguous.html).
+    pub fn is_gap(&self) -> bool { self.cause == Cause::Gap }
+
+    /// Returns true if the operation divided by zero.
+    pub fn is_division_by_zero(&self) -> bool { self.cause == Cause::DivisionByZero }
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.rhs() {
+            Some(rhs) => write!(f, "{} {} {}", self.lhs, self.operation.symbol(), rhs)?,
+            None => write!(f, "{}({})", self.operation.symbol(), self.lhs)?,
+        }
+        if let Some(result) = self.result {
+            write!(f, " = {}", result)?;
+        }
+        match self.cause {
+            Cause::Bound(Bound::Min(min)) => write!(f, " is below minimum {}", min),
+            Cause::Bound(Bound::Max(max)) => write!(f, " is above maximum {}", max),
+            Cause::Gap => f.write_str(" is not a valid value"),
+            Cause::DivisionByZero => f.write_str(" divides by zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ArithmeticError { }
+
+/// Error from converting a value outside the range of a bounded integer.
+///
+/// Returned by the `TryFrom` implementations of
+/// [`bounded_integer_try_from_impls!`](macro.bounded_integer_try_from_impls.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeError {
+    min: i128,
+    max: i128,
+}
+
+impl RangeError {
+    /// Creates an error for a value outside the range of `T`.
+    pub(crate) fn new<T: Bounded>() -> Self {
+        RangeError {
+            min: T::MIN_REPR.to_i128(),
+            max: T::MAX_REPR.to_i128(),
+        }
+    }
+
+    /// Returns the minimum of the range.
+    pub fn min(&self) -> i128 { self.min }
+
+    /// Returns the maximum of the range.
+    pub fn max(&self) -> i128 { self.max }
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value is outside the range {}...{}", self.min, self.max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RangeError { }
@@ -0,0 +1,106 @@
+use Bounded;
+use RangeError;
+use Repr;
+
+/// Rounding mode for conversion from floating point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+
+    /// Round towards positive infinity.
+    Ceil,
+
+    /// Round to the nearest integer, and ties to the even integer.
+    NearestEven,
+
+    /// Round towards zero.
+    Truncate,
+}
+
+/// Handling of out of range values in conversion from floating point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutOfRange {
+    /// Clamp to `Self::MIN` or `Self::MAX`.
+    Clamp,
+
+    /// Return an error.
+    Reject,
+}
+
+/// Conversion to and from floating point.
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait FloatConv: Bounded {
+    /// Converts from `f64`, rounding with `rounding`, and handling rounded values outside the
+    /// range of `Self` with `out_of_range`.
+    ///
+    /// NaN has no value to round or clamp, so it is always an error.
+    fn from_f64(
+        value: f64,
+        rounding: Rounding,
+        out_of_range: OutOfRange,
+    ) -> Result<Self, RangeError> {
+        if value.is_nan() { return Err(RangeError::new::<Self>()); }
+
+        // Rounded values beyond `i128` saturate, which keeps them out of range.
+        let value = round(value, rounding) as i128;
+        let (min, max) = (Self::MIN_REPR.to_i128(), Self::MAX_REPR.to_i128());
+        if value < min || value > max {
+            return match out_of_range {
+                OutOfRange::Clamp if value < min => Ok(Self::MIN),
+                OutOfRange::Clamp => Ok(Self::MAX),
+                OutOfRange::Reject => Err(RangeError::new::<Self>()),
+            };
+        }
+        Self::Repr::from_i128(value).and_then(Self::from_repr).ok_or_else(RangeError::new::<Self>)
+    }
+
+    /// Converts from `f32`, rounding with `rounding`, and handling rounded values outside the
+    /// range of `Self` with `out_of_range`.
+    ///
+    /// NaN has no value to round or clamp, so it is always an error.
+    fn from_f32(
+        value: f32,
+        rounding: Rounding,
+        out_of_range: OutOfRange,
+    ) -> Result<Self, RangeError> {
+        Self::from_f64(f64::from(value), rounding, out_of_range)
+    }
+
+    /// Converts to `f64`, rounding to nearest if the value has more than 53 significant bits.
+    fn to_f64(self) -> f64 {
+        self.to_repr().to_i128() as f64
+    }
+
+    /// Converts to `f32`, rounding to nearest if the value has more than 24 significant bits.
+    fn to_f32(self) -> f32 {
+        self.to_repr().to_i128() as f32
+    }
+}
+
+impl<T: Bounded> FloatConv for T { }
+
+/// Rounds a value to an integer. Infinities are returned unchanged, and NaN must not be passed.
+///
+/// `core` does not provide `f64::floor` and friends, so values with a fractional part, which are
+/// less than 2<sup>52</sup> in magnitude, are rounded through `i64`.
+fn round(value: f64, rounding: Rounding) -> f64 {
+    if value.abs() >= (1u64 << 52) as f64 { return value; }
+
+    let truncated = value as i64 as f64;
+    let floor = if truncated > value { truncated - 1.0 } else { truncated };
+    match rounding {
+        Rounding::Floor => floor,
+        Rounding::Ceil => if truncated < value { truncated + 1.0 } else { truncated },
+        Rounding::Truncate => truncated,
+        Rounding::NearestEven => {
+            let fraction = value - floor;
+            if fraction > 0.5 || fraction == 0.5 && floor as i64 % 2 != 0 {
+                floor + 1.0
+            } else {
+                floor
+            }
+        },
+    }
+}
@@ -0,0 +1,91 @@
+use ArithmeticError;
+use Bounded;
+use CheckedArith;
+use Operation;
+use Repr;
+use SaturatingArith;
+
+/// Folds over iterators of bounded integers.
+///
+/// Values are combined in order from the first, so a sum fails if any partial sum is out of range,
+/// like a `fold` with the operators. An empty sum is zero and an empty product is one, which fail
+/// if not in range.
+///
+/// Implemented for all iterators of [`Bounded`](trait.Bounded.html) types.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// use bounded_integer::BoundedIterator;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+/// # fn main() {
+/// let values = vec![TwoBit::P1, TwoBit::P2];
+/// assert_eq!(Some(TwoBit::P3), values.iter().cloned().checked_sum());
+/// assert_eq!(None, values.iter().cloned().chain(Some(TwoBit::P1)).checked_sum());
+/// # }
+/// ```
+pub trait BoundedIterator: Iterator + Sized where Self::Item: Bounded {
+    /// Checked sum. Returns `None` on overflow.
+    fn checked_sum(self) -> Option<Self::Item> {
+        self.try_sum().ok()
+    }
+
+    /// Saturating sum, which saturates at each step.
+    fn saturating_sum(mut self) -> Self::Item {
+        match self.next() {
+            Some(first) => self.fold(first, SaturatingArith::saturating_add),
+            None => saturating_zero(),
+        }
+    }
+
+    /// Checked product. Returns `None` on overflow.
+    fn checked_product(self) -> Option<Self::Item> {
+        self.try_product().ok()
+    }
+
+    /// Checked sum. Returns an error describing the first overflowing addition.
+    fn try_sum(mut self) -> Result<Self::Item, ArithmeticError> {
+        match self.next() {
+            Some(first) => self.try_fold(first, |sum, value| sum.try_add(value)),
+            None => identity(Operation::Add),
+        }
+    }
+
+    /// Checked product. Returns an error describing the first overflowing multiplication.
+    fn try_product(mut self) -> Result<Self::Item, ArithmeticError> {
+        match self.next() {
+            Some(first) => self.try_fold(first, |product, value| product.try_mul(value)),
+            None => identity(Operation::Mul),
+        }
+    }
+}
+
+impl<I: Iterator> BoundedIterator for I where I::Item: Bounded { }
+
+/// Returns the identity of addition or multiplication as `T`, failing as `identity op identity` if
+/// it is out of range.
+fn identity<T: Bounded>(operation: Operation) -> Result<T, ArithmeticError> {
+    let repr = if operation == Operation::Mul { T::Repr::ONE } else { T::Repr::ZERO };
+    T::from_repr(repr).ok_or_else(|| {
+        let value = repr.to_i128();
+        let (min, max) = (T::MIN_REPR.to_i128(), T::MAX_REPR.to_i128());
+        ArithmeticError::out_of_range(operation, value, Some(value), Some(value), true, min, max)
+    })
+}
+
+/// Returns zero clamped to the range of `T`.
+fn saturating_zero<T: Bounded>() -> T {
+    if T::Repr::ZERO < T::MIN_REPR {
+        T::MIN
+    } else if T::Repr::ZERO > T::MAX_REPR {
+        T::MAX
+    } else {
+        T::from_repr(T::Repr::ZERO).expect("representation out of range")
+    }
+}
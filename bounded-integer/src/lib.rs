@@ -1,27 +1,55 @@
 //! Provides *bounded integers*, integer types which are restricted to a range of values. These
-//! types are created by implementing the [`BoundedInteger`](trait.BoundedInteger.html) trait for
-//! C-like enums.
+//! types are created by implementing the [`Bounded`](trait.Bounded.html) trait for C-like enums.
+//! Arithmetic is provided by extension traits which are implemented for every `Bounded` type, and
+//! [`BoundedInteger`](trait.BoundedInteger.html) combines all of them. The
+//! [`prelude`](prelude/index.html) module re-exports every trait. The wrapper types
+//! [`Checked`](struct.Checked.html), [`Saturating`](struct.Saturating.html) and
+//! [`Wrapping`](struct.Wrapping.html) select an overflow policy for operators.
 //!
-//! This crate provides macros for generating implementations of `BoundedInteger`, `Into`, and
-//! arithmetic traits from `std::ops`. On nightly Rust, the [`bounded-integer-plugin`][plugin]
-//! crate provides a procedural macro for generating enums with variants for a range.
+//! `Bounded` is also implemented for the primitive integer types over their full range, and for
+//! `bool` as the range `0...1`, so they can be used wherever generic code expects a bounded
+//! integer.
+//!
+//! This crate provides macros for generating implementations of `Bounded`, conversion traits, and
+//! arithmetic traits from `std::ops`. The [`bounded-integer-macros`][macros] crate provides
+//! `#[derive(BoundedInteger)]`, which reads the representation and range from the enum itself, and
+//! the procedural macro `bounded_integer!` for generating enums with variants for a range. The
+//! same macro is provided for nightly Rust by the older [`bounded-integer-plugin`][plugin] crate.
+//!
+//! The crate supports `no_std` when built without its default `std` feature, which only enables
+//! the `std::error::Error` implementations and the `std::io` methods of
+//! [`ByteEncoding`](trait.ByteEncoding.html). The macros only refer to `core`.
 //!
 //! `bounded-integer` is on [Crates.io][crate] and [GitHub][github].
 //!
+//! [macros]: https://cmcenroe.me/bounded-integer/bounded_integer_macros
 //! [plugin]: https://cmcenroe.me/bounded-integer/bounded_integer_plugin
 //! [crate]: https://crates.io/crates/bounded-integer
 //! [github]: https://github.com/programble/bounded-integer
 //!
+//! # Migrating from `BoundedInteger`
+//!
+//! Methods such as `from_repr` and `checked_add` used to be provided by `BoundedInteger` itself, and
+//! are now provided by `Bounded` and the extension traits. Importing `BoundedInteger` alone no
+//! longer brings them into scope, so replace `use bounded_integer::BoundedInteger;` with:
+//!
+//! ```
+//! use bounded_integer::prelude::*;
+//! ```
+//!
+//! `BoundedInteger` and [`WrappingArith`](trait.WrappingArith.html) are only implemented for
+//! [`Contiguous`](trait.Contiguous.html) types, since wrapping is not defined for ranges with gaps.
+//! Types implemented with `bounded_integer_impls!` are contiguous.
+//!
 //! # Examples
 //!
-//! ## Nightly
+//! ## Procedural macro
 //!
 //! ```ignore
-//! #![feature(plugin)]
-//! #![plugin(bounded_integer_plugin)]
-//!
 //! #[macro_use]
 //! extern crate bounded_integer;
+//! #[macro_use]
+//! extern crate bounded_integer_macros;
 //!
 //! bounded_integer! {
 //!     /// Value that can fit in a nibble.
@@ -31,7 +59,7 @@
 //! # fn main() { }
 //! ```
 //!
-//! ## Stable
+//! ## Declarative macros
 //!
 //! The above example is equivalent to the following.
 //!
@@ -49,6 +77,8 @@
 //! # fn main() { }
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 #![warn(
     missing_docs,
     missing_debug_implementations,
@@ -62,135 +92,196 @@
     variant_size_differences,
 )]
 
+#[cfg(feature = "std")]
+extern crate core;
+
+#[doc(hidden)]
+pub extern crate core as __core;
+
+use core::hint;
+
 pub use repr::Repr;
 mod repr;
 
+pub use error::{ArithmeticError, Bound, Operation, RangeError};
+mod error;
+
+pub mod overflow;
+
+pub mod prelude;
+
+pub use checked::{Checked, CheckedArith};
+mod checked;
+
+pub use saturating::{Saturating, SaturatingArith};
+mod saturating;
+
+pub use wrapping::{Wrapping, WrappingArith};
+mod wrapping;
+
+pub use unchecked::UncheckedArith;
+mod unchecked;
+
+pub use math::IntegerMath;
+mod math;
+
+pub use float::{FloatConv, OutOfRange, Rounding};
+mod float;
+
+pub use bytes::ByteEncoding;
+mod bytes;
+
+pub use contiguous::Contiguous;
+mod contiguous;
+
+pub mod slice;
+
+pub use iter::BoundedIterator;
+mod iter;
+
+mod primitive;
+
 #[macro_use]
 mod macros;
 
 /// Bounded integers.
 ///
-/// Provides conversion, minimum, maximum, checked and saturating arithmetic.
-pub trait BoundedInteger: Copy + Eq + Ord {
+/// Provides conversion, minimum and maximum. Arithmetic is provided by the extension traits
+/// [`CheckedArith`](trait.CheckedArith.html), [`SaturatingArith`](trait.SaturatingArith.html),
+/// [`UncheckedArith`](trait.UncheckedArith.html) and [`IntegerMath`](trait.IntegerMath.html),
+/// which are implemented for all `Bounded` types, and
+/// [`WrappingArith`](trait.WrappingArith.html), which is implemented for all
+/// [`Contiguous`](trait.Contiguous.html) types.
+pub trait Bounded: Copy + Eq + Ord {
     /// Integer representation.
     ///
     /// Should reflect the `#[repr(...)]` attribute of `Self`.
     type Repr: Repr;
 
-    /// Converts from `Self::Repr` to `Self`.
-    fn from_repr(repr: Self::Repr) -> Option<Self>;
+    /// The smallest value that can be represented as `Self`.
+    const MIN: Self;
 
-    /// Converts from `Self` to `Self::Repr`.
-    fn to_repr(self) -> Self::Repr;
+    /// The largest value that can be represented as `Self`.
+    const MAX: Self;
 
-    /// Returns the smallest value that can be represented as `Self`.
-    fn min_value() -> Self;
+    /// `Self::MIN` as `Self::Repr`.
+    const MIN_REPR: Self::Repr;
 
-    /// Returns the largest value that can be represented as `Self`.
-    fn max_value() -> Self;
+    /// `Self::MAX` as `Self::Repr`.
+    const MAX_REPR: Self::Repr;
 
-    // Checked arithmetic.
-
-    /// Checked integer addition.
-    fn checked_add(self, other: Self) -> Option<Self> {
-        self.checked_add_repr(other.to_repr())
-    }
-
-    /// Checked integer subtraction.
-    fn checked_sub(self, other: Self) -> Option<Self> {
-        self.checked_sub_repr(other.to_repr())
-    }
+    /// Number of values that can be represented as `Self`.
+    ///
+    /// Saturates at `usize::MAX` for ranges with more values than that, such as the range of
+    /// `u64` on 64-bit targets.
+    const COUNT: usize;
 
-    /// Checked integer multiplication.
-    fn checked_mul(self, other: Self) -> Option<Self> {
-        self.checked_mul_repr(other.to_repr())
-    }
+    /// Converts from `Self::Repr` to `Self`.
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
 
-    /// Checked integer division.
-    fn checked_div(self, other: Self) -> Option<Self> {
-        self.checked_div_repr(other.to_repr())
+    /// Converts from `Self::Repr` to `Self` without checking that `repr` is in range.
+    ///
+    /// The range is only checked with `debug_assert!`.
+    ///
+    /// # Safety
+    ///
+    /// `Self::from_repr(repr)` must return `Some`.
+    unsafe fn from_repr_unchecked(repr: Self::Repr) -> Self {
+        debug_assert!(Self::from_repr(repr).is_some(), "representation out of range");
+        match Self::from_repr(repr) {
+            Some(value) => value,
+            None => hint::unreachable_unchecked(),
+        }
     }
 
-    /// Checked integer remainder.
-    fn checked_rem(self, other: Self) -> Option<Self> {
-        self.checked_rem_repr(other.to_repr())
-    }
+    /// Converts from `Self` to `Self::Repr`.
+    fn to_repr(self) -> Self::Repr;
 
-    /// Checked integer negation.
-    fn checked_neg(self) -> Option<Self> {
-        self.to_repr().checked_neg().and_then(Self::from_repr)
-    }
+    /// Returns the smallest value that can be represented as `Self`.
+    fn min_value() -> Self { Self::MIN }
 
-    // Checked arithmetic with `Self::Repr`.
+    /// Returns the largest value that can be represented as `Self`.
+    fn max_value() -> Self { Self::MAX }
+}
 
-    /// Checked integer addition with `Self::Repr`.
-    fn checked_add_repr(self, other: Self::Repr) -> Option<Self> {
-        self.to_repr().checked_add(other).and_then(Self::from_repr)
-    }
+/// Bounded integers with all arithmetic and conversions.
+///
+/// Implemented for all [`Contiguous`](trait.Contiguous.html) types, which includes every type
+/// implemented with the macros of this crate.
+pub trait BoundedInteger:
+    Bounded
+    + CheckedArith
+    + SaturatingArith
+    + WrappingArith
+    + UncheckedArith
+    + IntegerMath
+    + FloatConv
+    + ByteEncoding { }
 
-    /// Checked integer subtraction with `Self::Repr`.
-    fn checked_sub_repr(self, other: Self::Repr) -> Option<Self> {
-        self.to_repr().checked_sub(other).and_then(Self::from_repr)
-    }
+impl<T: Contiguous> BoundedInteger for T { }
 
-    /// Checked integer multiplication with `Self::Repr`.
-    fn checked_mul_repr(self, other: Self::Repr) -> Option<Self> {
-        self.to_repr().checked_mul(other).and_then(Self::from_repr)
-    }
+/// Computes `Bounded::COUNT` from the minimum and maximum representations.
+#[doc(hidden)]
+pub const fn __count(min: i128, max: i128) -> usize {
+    let count = max - min + 1;
+    if count > usize::MAX as i128 { usize::MAX } else { count as usize }
+}
 
-    /// Checked integer division with `Self::Repr`.
-    fn checked_div_repr(self, other: Self::Repr) -> Option<Self> {
-        self.to_repr().checked_div(other).and_then(Self::from_repr)
-    }
+/// Compiles only if `T` implements `Contiguous`.
+#[doc(hidden)]
+pub const fn __assert_contiguous<T: Contiguous>() { }
 
-    /// Checked integer remainder with `Self::Repr`.
-    fn checked_rem_repr(self, other: Self::Repr) -> Option<Self> {
-        self.to_repr().checked_rem(other).and_then(Self::from_repr)
-    }
+/// Converts an integer to `T`, given as `None` if it does not fit in `i128`.
+#[doc(hidden)]
+pub fn __try_from<T: Bounded>(value: Option<i128>) -> Result<T, RangeError> {
+    value
+        .and_then(T::Repr::from_i128)
+        .and_then(T::from_repr)
+        .ok_or_else(RangeError::new::<T>)
+}
 
-    // Saturating arithmetic.
+/// Range of integers which a primitive type represents exactly.
+#[doc(hidden)]
+pub trait __Lossless {
+    const MIN: i128;
+    const MAX: i128;
+}
 
-    /// Saturating integer addition.
-    fn saturating_add(self, other: Self) -> Self {
-        self.saturating_add_repr(other.to_repr())
+macro_rules! lossless_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl __Lossless for $ty {
+                const MIN: i128 = <$ty>::MIN as i128;
+                const MAX: i128 = <$ty>::MAX as i128;
+            }
+        )*
     }
+}
 
-    /// Saturating integer subtraction.
-    fn saturating_sub(self, other: Self) -> Self {
-        self.saturating_sub_repr(other.to_repr())
-    }
+lossless_impl!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
-    /// Saturating integer multiplication.
-    fn saturating_mul(self, other: Self) -> Self {
-        self.saturating_mul_repr(other.to_repr())
-    }
+impl __Lossless for i128 {
+    const MIN: i128 = i128::MIN;
+    const MAX: i128 = i128::MAX;
+}
 
-    // Saturating arithmetic with `Self::Repr`.
+impl __Lossless for u128 {
+    const MIN: i128 = 0;
+    const MAX: i128 = i128::MAX;
+}
 
-    /// Saturating integer addition with `Self::Repr`.
-    fn saturating_add_repr(self, other: Self::Repr) -> Self {
-        if other.is_negative() {
-            self.checked_add_repr(other).unwrap_or(Self::min_value())
-        } else {
-            self.checked_add_repr(other).unwrap_or(Self::max_value())
-        }
-    }
+impl __Lossless for f32 {
+    const MIN: i128 = -(1 << f32::MANTISSA_DIGITS);
+    const MAX: i128 = 1 << f32::MANTISSA_DIGITS;
+}
 
-    /// Saturating integer subtraction with `Self::Repr`.
-    fn saturating_sub_repr(self, other: Self::Repr) -> Self {
-        if other.is_negative() {
-            self.checked_sub_repr(other).unwrap_or(Self::max_value())
-        } else {
-            self.checked_sub_repr(other).unwrap_or(Self::min_value())
-        }
-    }
+impl __Lossless for f64 {
+    const MIN: i128 = -(1 << f64::MANTISSA_DIGITS);
+    const MAX: i128 = 1 << f64::MANTISSA_DIGITS;
+}
 
-    /// Saturating integer multiplication with `Self::Repr`.
-    fn saturating_mul_repr(self, other: Self::Repr) -> Self {
-        if self.to_repr().is_negative() == other.is_negative() {
-            self.checked_mul_repr(other).unwrap_or(Self::max_value())
-        } else {
-            self.checked_mul_repr(other).unwrap_or(Self::min_value())
-        }
-    }
+/// Returns true if `T` is in the range of integers which `P` represents exactly.
+#[doc(hidden)]
+pub const fn __fits<P: __Lossless>(min: i128, max: i128) -> bool {
+    min >= P::MIN && max <= P::MAX
 }
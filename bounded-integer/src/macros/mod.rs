@@ -1,8 +1,7 @@
-/// Implements [`BoundedInteger`](trait.BoundedInteger.html) for a C-like enum with contiguous
-/// variants.
+/// Implements [`Bounded`](trait.Bounded.html) for a C-like enum with contiguous variants.
 ///
-/// Uses `std::mem::transmute` to implement `BoundedInteger::from_repr` and `as` to implement
-/// `BoundedInteger::to_repr`.
+/// Uses `core::mem::transmute` to implement `Bounded::from_repr` and
+/// `Bounded::from_repr_unchecked`, and `as` to implement `Bounded::to_repr`.
 ///
 /// # Examples
 ///
@@ -18,12 +17,18 @@
 #[macro_export]
 macro_rules! bounded_integer_impl {
     ($ty:ty, $repr:ty, $min:path, $max:path) => {
-        impl $crate::BoundedInteger for $ty {
+        impl $crate::Bounded for $ty {
             type Repr = $repr;
 
-            #[allow(unused_comparisons)]
+            const MIN: Self = $min;
+            const MAX: Self = $max;
+            const MIN_REPR: $repr = $min as $repr;
+            const MAX_REPR: $repr = $max as $repr;
+            const COUNT: usize = $crate::__count($min as $repr as i128, $max as $repr as i128);
+
+            #[allow(unused_comparisons, clippy::double_comparisons)]
             fn from_repr(repr: $repr) -> Option<Self> {
-                use std::mem;
+                use $crate::__core::mem;
                 if repr >= $min as $repr && repr <= $max as $repr {
                     Some(unsafe { mem::transmute(repr) })
                 } else {
@@ -31,31 +36,293 @@ macro_rules! bounded_integer_impl {
                 }
             }
 
-            fn to_repr(self) -> $repr { self as $repr }
+            unsafe fn from_repr_unchecked(repr: $repr) -> Self {
+                use $crate::__core::mem;
+                debug_assert!(Self::from_repr(repr).is_some(), "representation out of range");
+                mem::transmute(repr)
+            }
 
-            fn min_value() -> Self { $min }
-            fn max_value() -> Self { $max }
+            fn to_repr(self) -> $repr { self as $repr }
         }
+
+        unsafe impl $crate::Contiguous for $ty { }
     }
 }
 
+/// Creates a bounded integer from a constant, checking its range at compile time.
+///
+/// Evaluates to a constant of type `$ty`, which must implement
+/// [`Contiguous`](trait.Contiguous.html). `$value` must be a constant expression of type
+/// `<$ty as Bounded>::Repr`, and a value outside `$ty::MIN_REPR...$ty::MAX_REPR` fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+///
+/// const TWO: TwoBit = bounded!(TwoBit, 2);
+/// # fn main() {
+/// assert_eq!(TwoBit::P2, TWO);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// # #[repr(u8)]
+/// # enum TwoBit { Z0, P1, P2, P3 }
+/// # bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+/// # fn main() {
+/// let four = bounded!(TwoBit, 4);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bounded {
+    ($ty:ty, $value:expr) => {{
+        const VALUE: $ty = {
+            $crate::__assert_contiguous::<$ty>();
+            let repr: <$ty as $crate::Bounded>::Repr = $value;
+            #[allow(unused_comparisons)]
+            let in_range = repr >= <$ty as $crate::Bounded>::MIN_REPR
+                && repr <= <$ty as $crate::Bounded>::MAX_REPR;
+            assert!(in_range, "bounded integer literal out of range");
+            #[allow(clippy::useless_transmute, clippy::transmute_int_to_bool)]
+            let value = unsafe {
+                $crate::__core::mem::transmute::<<$ty as $crate::Bounded>::Repr, $ty>(repr)
+            };
+            value
+        };
+        VALUE
+    }};
+}
+
 /// Implements `Into<Self::Repr>` for a [`BoundedInteger`](trait.BoundedInteger.html).
 #[macro_export]
 macro_rules! bounded_integer_into_repr_impl {
     ($ty:ty) => {
-        impl Into<<$ty as $crate::BoundedInteger>::Repr> for $ty {
-            fn into(self) -> <$ty as $crate::BoundedInteger>::Repr {
-                use $crate::BoundedInteger;
+        impl Into<<$ty as $crate::Bounded>::Repr> for $ty {
+            fn into(self) -> <$ty as $crate::Bounded>::Repr {
+                use $crate::Bounded;
                 self.to_repr()
             }
         }
     }
 }
 
+/// Implements `From<Self>` for primitive types which represent the whole range of a
+/// [`BoundedInteger`](trait.BoundedInteger.html) exactly.
+///
+/// The range, not the representation, decides which types fit, so a type with range `0...15` and
+/// representation `i8` can convert to `u8`. Listing a type which cannot represent the range fails
+/// to compile. `f32` and `f64` represent integers up to 2<sup>24</sup> and 2<sup>53</sup> in
+/// magnitude. The representation itself is covered by
+/// [`bounded_integer_into_repr_impl!`](macro.bounded_integer_into_repr_impl.html).
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(i8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, i8, TwoBit::Z0, TwoBit::P3);
+/// bounded_integer_from_impls!(TwoBit, u8, u32, i64, f32);
+/// # fn main() {
+/// assert_eq!(2u8, u8::from(TwoBit::P2));
+/// assert_eq!(3.0f32, f32::from(TwoBit::P3));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// # #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// # #[repr(i8)]
+/// # enum SignedBit { N1 = -1, Z0 }
+/// # bounded_integer_impls!(SignedBit, i8, SignedBit::N1, SignedBit::Z0);
+/// bounded_integer_from_impls!(SignedBit, u8);
+/// # fn main() { }
+/// ```
+#[macro_export]
+macro_rules! bounded_integer_from_impls {
+    ($ty:ty, $($prim:ty),+ $(,)*) => {
+        $(
+            impl $crate::__core::convert::From<$ty> for $prim {
+                fn from(value: $ty) -> $prim {
+                    use $crate::Bounded;
+                    const _: () = assert!(
+                        $crate::__fits::<$prim>(
+                            <$ty as $crate::Bounded>::MIN_REPR as i128,
+                            <$ty as $crate::Bounded>::MAX_REPR as i128,
+                        ),
+                        "bounded integer range does not fit in target type",
+                    );
+                    value.to_repr() as $prim
+                }
+            }
+        )+
+    }
+}
+
+/// Implements `TryFrom` from every primitive integer type for a
+/// [`BoundedInteger`](trait.BoundedInteger.html), failing with
+/// [`RangeError`](struct.RangeError.html) for values outside its range.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// use std::convert::TryFrom;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+/// # fn main() {
+/// assert_eq!(Ok(TwoBit::P2), TwoBit::try_from(2i64));
+/// assert!(TwoBit::try_from(4u128).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bounded_integer_try_from_impls {
+    ($ty:ty) => {
+        bounded_integer_try_from_impls!(
+            @impl $ty, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        );
+    };
+    (@impl $ty:ty, $($prim:ty),+) => {
+        $(
+            impl $crate::__core::convert::TryFrom<$prim> for $ty {
+                type Error = $crate::RangeError;
+
+                fn try_from(value: $prim) -> Result<Self, $crate::RangeError> {
+                    $crate::__try_from(
+                        $crate::__core::convert::TryFrom::try_from(value).ok()
+                    )
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `PartialEq` and `PartialOrd` between a
+/// [`BoundedInteger`](trait.BoundedInteger.html) and its `Self::Repr`, in both directions.
+///
+/// - `Self == Self::Repr`
+/// - `Self::Repr == Self`
+/// - `Self < Self::Repr`
+/// - `Self::Repr < Self`
+///
+/// Takes the representation as the second argument, like
+/// [`bounded_integer_impl!`](macro.bounded_integer_impl.html).
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+/// bounded_integer_cmp_repr_impls!(TwoBit, u8);
+/// # fn main() {
+/// assert!(TwoBit::P2 == 2);
+/// assert!(TwoBit::P2 < 3);
+/// assert!(0 < TwoBit::P1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bounded_integer_cmp_repr_impls {
+    ($ty:ty, $repr:ty) => {
+        impl $crate::__core::cmp::PartialEq<$repr> for $ty {
+            fn eq(&self, other: &$repr) -> bool {
+                use $crate::Bounded;
+                self.to_repr() == *other
+            }
+        }
+        impl $crate::__core::cmp::PartialEq<$ty> for $repr {
+            fn eq(&self, other: &$ty) -> bool {
+                use $crate::Bounded;
+                *self == other.to_repr()
+            }
+        }
+        impl $crate::__core::cmp::PartialOrd<$repr> for $ty {
+            fn partial_cmp(&self, other: &$repr) -> Option<$crate::__core::cmp::Ordering> {
+                use $crate::Bounded;
+                Some(self.to_repr().cmp(other))
+            }
+        }
+        impl $crate::__core::cmp::PartialOrd<$ty> for $repr {
+            fn partial_cmp(&self, other: &$ty) -> Option<$crate::__core::cmp::Ordering> {
+                use $crate::Bounded;
+                Some(self.cmp(&other.to_repr()))
+            }
+        }
+    }
+}
+
+/// Implements `PartialEq` and `PartialOrd` between two different
+/// [`BoundedInteger`](trait.BoundedInteger.html) types, in both directions.
+///
+/// Values are compared mathematically, so types with different representations compare correctly.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(i8)]
+/// enum SignedBit { N1 = -1, Z0 }
+/// bounded_integer_impls!(SignedBit, i8, SignedBit::N1, SignedBit::Z0);
+///
+/// bounded_integer_cmp_impls!(TwoBit, SignedBit);
+/// # fn main() {
+/// assert!(TwoBit::Z0 == SignedBit::Z0);
+/// assert!(SignedBit::N1 < TwoBit::Z0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bounded_integer_cmp_impls {
+    ($a:ty, $b:ty) => {
+        bounded_integer_cmp_impls!(@impl $a, $b);
+        bounded_integer_cmp_impls!(@impl $b, $a);
+    };
+    (@impl $lhs:ty, $rhs:ty) => {
+        impl $crate::__core::cmp::PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                use $crate::{Bounded, Repr};
+                self.to_repr().to_i128() == other.to_repr().to_i128()
+            }
+        }
+        impl $crate::__core::cmp::PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<$crate::__core::cmp::Ordering> {
+                use $crate::{Bounded, Repr};
+                Some(self.to_repr().to_i128().cmp(&other.to_repr().to_i128()))
+            }
+        }
+    };
+}
+
 /// Implements all bounded integer traits for a C-like enum with contiguous variants.
 ///
-/// - [`BoundedInteger`](trait.BoundedInteger.html)
+/// - [`Bounded`](trait.Bounded.html)
 /// - `Into<Self::Repr>`
+/// - `TryFrom` from every primitive integer type
 /// - `Add<Self>`
 /// - `Add<Self::Repr>`
 /// - `Sub<Self>`
@@ -67,6 +334,11 @@ macro_rules! bounded_integer_into_repr_impl {
 /// - `Rem<Self>`
 /// - `Rem<Self::Repr>`
 /// - `Neg`
+/// - `Sum`
+/// - `Product`
+///
+/// Operators panic on overflow, unless an [`OverflowPolicy`](overflow/trait.OverflowPolicy.html)
+/// type is given as the last argument.
 ///
 /// # Examples
 ///
@@ -79,23 +351,42 @@ macro_rules! bounded_integer_into_repr_impl {
 /// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
 /// # fn main() { }
 /// ```
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate bounded_integer;
+/// use bounded_integer::overflow::Saturate;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// #[repr(u8)]
+/// enum TwoBit { Z0, P1, P2, P3 }
+/// bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3, Saturate);
+/// # fn main() {
+/// assert_eq!(TwoBit::P3, TwoBit::P2 + TwoBit::P2);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! bounded_integer_impls {
     ($ty:ty, $repr:ty, $min:path, $max:path) => {
+        bounded_integer_impls!($ty, $repr, $min, $max, $crate::overflow::Panic);
+    };
+    ($ty:ty, $repr:ty, $min:path, $max:path, $policy:ty) => {
         bounded_integer_impl!($ty, $repr, $min, $max);
         bounded_integer_into_repr_impl!($ty);
-        bounded_integer_add_self_impls!($ty);
-        bounded_integer_add_repr_impls!($ty);
-        bounded_integer_sub_self_impls!($ty);
-        bounded_integer_sub_repr_impls!($ty);
-        bounded_integer_mul_self_impls!($ty);
-        bounded_integer_mul_repr_impls!($ty);
-        bounded_integer_div_self_impls!($ty);
-        bounded_integer_div_repr_impls!($ty);
-        bounded_integer_rem_self_impls!($ty);
-        bounded_integer_rem_repr_impls!($ty);
-        bounded_integer_neg_impls!($ty);
-    }
+        bounded_integer_try_from_impls!($ty);
+        bounded_integer_add_self_impls!($ty, $policy);
+        bounded_integer_add_repr_impls!($ty, $policy);
+        bounded_integer_sub_self_impls!($ty, $policy);
+        bounded_integer_sub_repr_impls!($ty, $policy);
+        bounded_integer_mul_self_impls!($ty, $policy);
+        bounded_integer_mul_repr_impls!($ty, $policy);
+        bounded_integer_div_self_impls!($ty, $policy);
+        bounded_integer_div_repr_impls!($ty, $policy);
+        bounded_integer_rem_self_impls!($ty, $policy);
+        bounded_integer_rem_repr_impls!($ty, $policy);
+        bounded_integer_neg_impls!($ty, $policy);
+        bounded_integer_sum_impls!($ty, $policy);
+    };
 }
 
 #[macro_use]
@@ -5,26 +5,34 @@
 /// - `&Self + Self -> Self`
 /// - `&Self + &Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_add_self_impls {
     ($ty:ty) => {
-        impl ::std::ops::Add<$ty> for $ty {
+        bounded_integer_add_self_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Add<$ty> for $ty {
             type Output = Self;
             fn add(self, rhs: Self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_add(rhs).expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_add(rhs) {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_add(rhs), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Add<&'a $ty> for $ty {
+        impl<'a> $crate::__core::ops::Add<&'a $ty> for $ty {
             type Output = Self;
             fn add(self, rhs: &Self) -> Self { self + *rhs }
         }
-        impl<'a> ::std::ops::Add<$ty> for &'a $ty {
+        impl<'a> $crate::__core::ops::Add<$ty> for &'a $ty {
             type Output = $ty;
             fn add(self, rhs: $ty) -> $ty { *self + rhs }
         }
-        impl<'a, 'b> ::std::ops::Add<&'b $ty> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Add<&'b $ty> for &'a $ty {
             type Output = $ty;
             fn add(self, rhs: &$ty) -> $ty { *self + *rhs }
         }
@@ -38,28 +46,38 @@ macro_rules! bounded_integer_add_self_impls {
 /// - `&Self + Self::Repr -> Self`
 /// - `&Self + &Self::Repr -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_add_repr_impls {
     ($ty:ty) => {
-        impl ::std::ops::Add<<$ty as $crate::BoundedInteger>::Repr> for $ty {
+        bounded_integer_add_repr_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Add<<$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn add(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_add_repr(rhs).expect("arithmetic operation overflowed")
+            fn add(self, rhs: <$ty as $crate::Bounded>::Repr) -> Self {
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_add_repr(rhs) {
+                    Some(value) => value,
+                    None => {
+                        <$policy>::resolve_debug(self.try_add_repr(rhs), cfg!(debug_assertions))
+                    },
+                }
             }
         }
-        impl<'a> ::std::ops::Add<&'a <$ty as $crate::BoundedInteger>::Repr> for $ty {
+        impl<'a> $crate::__core::ops::Add<&'a <$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn add(self, rhs: &'a <$ty as $crate::BoundedInteger>::Repr) -> Self { self + *rhs }
+            fn add(self, rhs: &'a <$ty as $crate::Bounded>::Repr) -> Self { self + *rhs }
         }
-        impl<'a> ::std::ops::Add<<$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a> $crate::__core::ops::Add<<$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn add(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> $ty { *self + rhs }
+            fn add(self, rhs: <$ty as $crate::Bounded>::Repr) -> $ty { *self + rhs }
         }
-        impl<'a, 'b> ::std::ops::Add<&'b <$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Add<&'b <$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn add(self, rhs: &<$ty as $crate::BoundedInteger>::Repr) -> $ty { *self + *rhs }
+            fn add(self, rhs: &<$ty as $crate::Bounded>::Repr) -> $ty { *self + *rhs }
         }
     }
 }
@@ -71,26 +89,34 @@ macro_rules! bounded_integer_add_repr_impls {
 /// - `&Self - Self -> Self`
 /// - `&Self - &Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_sub_self_impls {
     ($ty:ty) => {
-        impl ::std::ops::Sub<$ty> for $ty {
+        bounded_integer_sub_self_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Sub<$ty> for $ty {
             type Output = Self;
             fn sub(self, rhs: Self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_sub(rhs).expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_sub(rhs) {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_sub(rhs), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Sub<&'a $ty> for $ty {
+        impl<'a> $crate::__core::ops::Sub<&'a $ty> for $ty {
             type Output = Self;
             fn sub(self, rhs: &Self) -> Self { self - *rhs }
         }
-        impl<'a> ::std::ops::Sub<$ty> for &'a $ty {
+        impl<'a> $crate::__core::ops::Sub<$ty> for &'a $ty {
             type Output = $ty;
             fn sub(self, rhs: $ty) -> $ty { *self - rhs }
         }
-        impl<'a, 'b> ::std::ops::Sub<&'b $ty> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Sub<&'b $ty> for &'a $ty {
             type Output = $ty;
             fn sub(self, rhs: &$ty) -> $ty { *self - *rhs }
         }
@@ -104,28 +130,38 @@ macro_rules! bounded_integer_sub_self_impls {
 /// - `&Self - Self::Repr -> Self`
 /// - `&Self - &Self::Repr -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_sub_repr_impls {
     ($ty:ty) => {
-        impl ::std::ops::Sub<<$ty as $crate::BoundedInteger>::Repr> for $ty {
+        bounded_integer_sub_repr_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Sub<<$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn sub(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_sub_repr(rhs).expect("arithmetic operation overflowed")
+            fn sub(self, rhs: <$ty as $crate::Bounded>::Repr) -> Self {
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_sub_repr(rhs) {
+                    Some(value) => value,
+                    None => {
+                        <$policy>::resolve_debug(self.try_sub_repr(rhs), cfg!(debug_assertions))
+                    },
+                }
             }
         }
-        impl<'a> ::std::ops::Sub<&'a <$ty as $crate::BoundedInteger>::Repr> for $ty {
+        impl<'a> $crate::__core::ops::Sub<&'a <$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn sub(self, rhs: &'a <$ty as $crate::BoundedInteger>::Repr) -> Self { self - *rhs }
+            fn sub(self, rhs: &'a <$ty as $crate::Bounded>::Repr) -> Self { self - *rhs }
         }
-        impl<'a> ::std::ops::Sub<<$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a> $crate::__core::ops::Sub<<$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn sub(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> $ty { *self - rhs }
+            fn sub(self, rhs: <$ty as $crate::Bounded>::Repr) -> $ty { *self - rhs }
         }
-        impl<'a, 'b> ::std::ops::Sub<&'b <$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Sub<&'b <$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn sub(self, rhs: &<$ty as $crate::BoundedInteger>::Repr) -> $ty { *self - *rhs }
+            fn sub(self, rhs: &<$ty as $crate::Bounded>::Repr) -> $ty { *self - *rhs }
         }
     }
 }
@@ -137,26 +173,34 @@ macro_rules! bounded_integer_sub_repr_impls {
 /// - `&Self * Self -> Self`
 /// - `&Self * &Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_mul_self_impls {
     ($ty:ty) => {
-        impl ::std::ops::Mul<$ty> for $ty {
+        bounded_integer_mul_self_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Mul<$ty> for $ty {
             type Output = Self;
             fn mul(self, rhs: Self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_mul(rhs).expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_mul(rhs) {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_mul(rhs), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Mul<&'a $ty> for $ty {
+        impl<'a> $crate::__core::ops::Mul<&'a $ty> for $ty {
             type Output = Self;
             fn mul(self, rhs: &Self) -> Self { self * *rhs }
         }
-        impl<'a> ::std::ops::Mul<$ty> for &'a $ty {
+        impl<'a> $crate::__core::ops::Mul<$ty> for &'a $ty {
             type Output = $ty;
             fn mul(self, rhs: $ty) -> $ty { *self * rhs }
         }
-        impl<'a, 'b> ::std::ops::Mul<&'b $ty> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Mul<&'b $ty> for &'a $ty {
             type Output = $ty;
             fn mul(self, rhs: &$ty) -> $ty { *self * *rhs }
         }
@@ -170,28 +214,38 @@ macro_rules! bounded_integer_mul_self_impls {
 /// - `&Self * Self::Repr -> Self`
 /// - `&Self * &Self::Repr -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_mul_repr_impls {
     ($ty:ty) => {
-        impl ::std::ops::Mul<<$ty as $crate::BoundedInteger>::Repr> for $ty {
+        bounded_integer_mul_repr_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Mul<<$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn mul(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_mul_repr(rhs).expect("arithmetic operation overflowed")
+            fn mul(self, rhs: <$ty as $crate::Bounded>::Repr) -> Self {
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_mul_repr(rhs) {
+                    Some(value) => value,
+                    None => {
+                        <$policy>::resolve_debug(self.try_mul_repr(rhs), cfg!(debug_assertions))
+                    },
+                }
             }
         }
-        impl<'a> ::std::ops::Mul<&'a <$ty as $crate::BoundedInteger>::Repr> for $ty {
+        impl<'a> $crate::__core::ops::Mul<&'a <$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn mul(self, rhs: &'a <$ty as $crate::BoundedInteger>::Repr) -> Self { self * *rhs }
+            fn mul(self, rhs: &'a <$ty as $crate::Bounded>::Repr) -> Self { self * *rhs }
         }
-        impl<'a> ::std::ops::Mul<<$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a> $crate::__core::ops::Mul<<$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn mul(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> $ty { *self * rhs }
+            fn mul(self, rhs: <$ty as $crate::Bounded>::Repr) -> $ty { *self * rhs }
         }
-        impl<'a, 'b> ::std::ops::Mul<&'b <$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Mul<&'b <$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn mul(self, rhs: &<$ty as $crate::BoundedInteger>::Repr) -> $ty { *self * *rhs }
+            fn mul(self, rhs: &<$ty as $crate::Bounded>::Repr) -> $ty { *self * *rhs }
         }
     }
 }
@@ -203,26 +257,34 @@ macro_rules! bounded_integer_mul_repr_impls {
 /// - `&Self / Self -> Self`
 /// - `&Self / &Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_div_self_impls {
     ($ty:ty) => {
-        impl ::std::ops::Div<$ty> for $ty {
+        bounded_integer_div_self_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Div<$ty> for $ty {
             type Output = Self;
             fn div(self, rhs: Self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_div(rhs).expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_div(rhs) {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_div(rhs), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Div<&'a $ty> for $ty {
+        impl<'a> $crate::__core::ops::Div<&'a $ty> for $ty {
             type Output = Self;
             fn div(self, rhs: &Self) -> Self { self / *rhs }
         }
-        impl<'a> ::std::ops::Div<$ty> for &'a $ty {
+        impl<'a> $crate::__core::ops::Div<$ty> for &'a $ty {
             type Output = $ty;
             fn div(self, rhs: $ty) -> $ty { *self / rhs }
         }
-        impl<'a, 'b> ::std::ops::Div<&'b $ty> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Div<&'b $ty> for &'a $ty {
             type Output = $ty;
             fn div(self, rhs: &$ty) -> $ty { *self / *rhs }
         }
@@ -236,28 +298,38 @@ macro_rules! bounded_integer_div_self_impls {
 /// - `&Self / Self::Repr -> Self`
 /// - `&Self / &Self::Repr -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_div_repr_impls {
     ($ty:ty) => {
-        impl ::std::ops::Div<<$ty as $crate::BoundedInteger>::Repr> for $ty {
+        bounded_integer_div_repr_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Div<<$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn div(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_div_repr(rhs).expect("arithmetic operation overflowed")
+            fn div(self, rhs: <$ty as $crate::Bounded>::Repr) -> Self {
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_div_repr(rhs) {
+                    Some(value) => value,
+                    None => {
+                        <$policy>::resolve_debug(self.try_div_repr(rhs), cfg!(debug_assertions))
+                    },
+                }
             }
         }
-        impl<'a> ::std::ops::Div<&'a <$ty as $crate::BoundedInteger>::Repr> for $ty {
+        impl<'a> $crate::__core::ops::Div<&'a <$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn div(self, rhs: &'a <$ty as $crate::BoundedInteger>::Repr) -> Self { self / *rhs }
+            fn div(self, rhs: &'a <$ty as $crate::Bounded>::Repr) -> Self { self / *rhs }
         }
-        impl<'a> ::std::ops::Div<<$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a> $crate::__core::ops::Div<<$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn div(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> $ty { *self / rhs }
+            fn div(self, rhs: <$ty as $crate::Bounded>::Repr) -> $ty { *self / rhs }
         }
-        impl<'a, 'b> ::std::ops::Div<&'b <$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Div<&'b <$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn div(self, rhs: &<$ty as $crate::BoundedInteger>::Repr) -> $ty { *self / *rhs }
+            fn div(self, rhs: &<$ty as $crate::Bounded>::Repr) -> $ty { *self / *rhs }
         }
     }
 }
@@ -269,26 +341,34 @@ macro_rules! bounded_integer_div_repr_impls {
 /// - `&Self / Self -> Self`
 /// - `&Self / &Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_rem_self_impls {
     ($ty:ty) => {
-        impl ::std::ops::Rem<$ty> for $ty {
+        bounded_integer_rem_self_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Rem<$ty> for $ty {
             type Output = Self;
             fn rem(self, rhs: Self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_rem(rhs).expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_rem(rhs) {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_rem(rhs), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Rem<&'a $ty> for $ty {
+        impl<'a> $crate::__core::ops::Rem<&'a $ty> for $ty {
             type Output = Self;
             fn rem(self, rhs: &Self) -> Self { self % *rhs }
         }
-        impl<'a> ::std::ops::Rem<$ty> for &'a $ty {
+        impl<'a> $crate::__core::ops::Rem<$ty> for &'a $ty {
             type Output = $ty;
             fn rem(self, rhs: $ty) -> $ty { *self % rhs }
         }
-        impl<'a, 'b> ::std::ops::Rem<&'b $ty> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Rem<&'b $ty> for &'a $ty {
             type Output = $ty;
             fn rem(self, rhs: &$ty) -> $ty { *self % *rhs }
         }
@@ -302,28 +382,38 @@ macro_rules! bounded_integer_rem_self_impls {
 /// - `&Self / Self::Repr -> Self`
 /// - `&Self / &Self::Repr -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_rem_repr_impls {
     ($ty:ty) => {
-        impl ::std::ops::Rem<<$ty as $crate::BoundedInteger>::Repr> for $ty {
+        bounded_integer_rem_repr_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Rem<<$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn rem(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_rem_repr(rhs).expect("arithmetic operation overflowed")
+            fn rem(self, rhs: <$ty as $crate::Bounded>::Repr) -> Self {
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_rem_repr(rhs) {
+                    Some(value) => value,
+                    None => {
+                        <$policy>::resolve_debug(self.try_rem_repr(rhs), cfg!(debug_assertions))
+                    },
+                }
             }
         }
-        impl<'a> ::std::ops::Rem<&'a <$ty as $crate::BoundedInteger>::Repr> for $ty {
+        impl<'a> $crate::__core::ops::Rem<&'a <$ty as $crate::Bounded>::Repr> for $ty {
             type Output = Self;
-            fn rem(self, rhs: &'a <$ty as $crate::BoundedInteger>::Repr) -> Self { self % *rhs }
+            fn rem(self, rhs: &'a <$ty as $crate::Bounded>::Repr) -> Self { self % *rhs }
         }
-        impl<'a> ::std::ops::Rem<<$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a> $crate::__core::ops::Rem<<$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn rem(self, rhs: <$ty as $crate::BoundedInteger>::Repr) -> $ty { *self % rhs }
+            fn rem(self, rhs: <$ty as $crate::Bounded>::Repr) -> $ty { *self % rhs }
         }
-        impl<'a, 'b> ::std::ops::Rem<&'b <$ty as $crate::BoundedInteger>::Repr> for &'a $ty {
+        impl<'a, 'b> $crate::__core::ops::Rem<&'b <$ty as $crate::Bounded>::Repr> for &'a $ty {
             type Output = $ty;
-            fn rem(self, rhs: &<$ty as $crate::BoundedInteger>::Repr) -> $ty { *self % *rhs }
+            fn rem(self, rhs: &<$ty as $crate::Bounded>::Repr) -> $ty { *self % *rhs }
         }
     }
 }
@@ -333,20 +423,85 @@ macro_rules! bounded_integer_rem_repr_impls {
 /// - `-Self -> Self`
 /// - `-&Self -> Self`
 ///
-/// Implementations always panic on overflow.
+/// Implementations panic on overflow, unless an
+/// [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type is given as the second argument.
 #[macro_export]
 macro_rules! bounded_integer_neg_impls {
     ($ty:ty) => {
-        impl ::std::ops::Neg for $ty {
+        bounded_integer_neg_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::ops::Neg for $ty {
             type Output = Self;
             fn neg(self) -> Self {
-                use $crate::BoundedInteger;
-                self.checked_neg().expect("arithmetic operation overflowed")
+                use $crate::CheckedArith;
+                use $crate::overflow::OverflowPolicy;
+                match self.checked_neg() {
+                    Some(value) => value,
+                    None => <$policy>::resolve_debug(self.try_neg(), cfg!(debug_assertions)),
+                }
             }
         }
-        impl<'a> ::std::ops::Neg for &'a $ty {
+        impl<'a> $crate::__core::ops::Neg for &'a $ty {
             type Output = $ty;
             fn neg(self) -> $ty { -*self }
         }
     }
 }
+
+/// Implements `std::iter::Sum` and `std::iter::Product` for a
+/// [`BoundedInteger`](trait.BoundedInteger.html).
+///
+/// - `Sum<Self>`
+/// - `Sum<&Self>`
+/// - `Product<Self>`
+/// - `Product<&Self>`
+///
+/// Values are combined in order with fallible arithmetic, and a partial result which overflows is
+/// resolved by the [`OverflowPolicy`](overflow/trait.OverflowPolicy.html) type given as the second
+/// argument, or panics by default, regardless of the policy of the operators. An empty sum is zero
+/// and an empty product is one, which overflow if not in range.
+#[macro_export]
+macro_rules! bounded_integer_sum_impls {
+    ($ty:ty) => {
+        bounded_integer_sum_impls!($ty, $crate::overflow::Panic);
+    };
+    ($ty:ty, $policy:ty) => {
+        impl $crate::__core::iter::Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                use $crate::{BoundedIterator, CheckedArith};
+                use $crate::overflow::OverflowPolicy;
+                match iter.next() {
+                    Some(first) => iter.fold(first, |sum, value| {
+                        <$policy>::resolve_debug(sum.try_add(value), cfg!(debug_assertions))
+                    }),
+                    None => <$policy>::resolve_debug(
+                        $crate::__core::iter::empty::<Self>().try_sum(),
+                        cfg!(debug_assertions),
+                    ),
+                }
+            }
+        }
+        impl<'a> $crate::__core::iter::Sum<&'a $ty> for $ty {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.cloned().sum() }
+        }
+        impl $crate::__core::iter::Product for $ty {
+            fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+                use $crate::{BoundedIterator, CheckedArith};
+                use $crate::overflow::OverflowPolicy;
+                match iter.next() {
+                    Some(first) => iter.fold(first, |product, value| {
+                        <$policy>::resolve_debug(product.try_mul(value), cfg!(debug_assertions))
+                    }),
+                    None => <$policy>::resolve_debug(
+                        $crate::__core::iter::empty::<Self>().try_product(),
+                        cfg!(debug_assertions),
+                    ),
+                }
+            }
+        }
+        impl<'a> $crate::__core::iter::Product<&'a $ty> for $ty {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self { iter.cloned().product() }
+        }
+    }
+}
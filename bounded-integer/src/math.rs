@@ -0,0 +1,117 @@
+use Bounded;
+use Repr;
+
+/// Integer math.
+///
+/// Results are `Self` where the range of `Self` permits, `Self::Repr` where the result need not be
+/// in range, and `u32` for logarithms. Computations are done in `u128`, so they cannot overflow.
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait IntegerMath: Bounded {
+    /// Checked integer square root, rounded down. Returns `None` if `self` is negative or the
+    /// result is out of range.
+    fn checked_isqrt(self) -> Option<Self> {
+        self.to_repr().to_u128().map(isqrt).and_then(from_u128)
+    }
+
+    /// Integer base 2 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    fn ilog2(self) -> u32 {
+        ilog(positive(self), 2)
+    }
+
+    /// Integer base 10 logarithm, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive.
+    fn ilog10(self) -> u32 {
+        ilog(positive(self), 10)
+    }
+
+    /// Integer logarithm in `base`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not positive or `base` is less than 2.
+    fn ilog(self, base: Self::Repr) -> u32 {
+        let base = base.to_u128().unwrap_or(0);
+        assert!(base >= 2, "base of integer logarithm must be at least 2");
+        ilog(positive(self), base)
+    }
+
+    /// Greatest common divisor, which is never negative. Returns zero if both values are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is not representable as `Self::Repr`, which only happens if both
+    /// values are `Self::Repr::MIN`.
+    fn gcd(self, other: Self) -> Self::Repr {
+        let result = gcd(abs(self), abs(other));
+        Self::Repr::from_i128(result as i128).expect("greatest common divisor overflowed")
+    }
+
+    /// Checked least common multiple, which is never negative. Returns zero if either value is
+    /// zero, and `None` if the result is out of range.
+    fn checked_lcm(self, other: Self) -> Option<Self> {
+        let (lhs, rhs) = (abs(self), abs(other));
+        if lhs == 0 || rhs == 0 { return from_u128(0); }
+        from_u128(lhs / gcd(lhs, rhs) * rhs)
+    }
+}
+
+impl<T: Bounded> IntegerMath for T { }
+
+/// Returns the absolute value of `value`.
+fn abs<T: Bounded>(value: T) -> u128 {
+    value.to_repr().to_i128().unsigned_abs()
+}
+
+/// Returns `value` if it is positive.
+fn positive<T: Bounded>(value: T) -> u128 {
+    match value.to_repr().to_u128() {
+        Some(value) if value > 0 => value,
+        _ => panic!("argument of integer logarithm must be positive"),
+    }
+}
+
+/// Converts from `u128` to `T`.
+fn from_u128<T: Bounded>(value: u128) -> Option<T> {
+    if value > i128::MAX as u128 { return None; }
+    T::Repr::from_i128(value as i128).and_then(T::from_repr)
+}
+
+/// Integer square root by Newton's method.
+fn isqrt(value: u128) -> u128 {
+    if value < 2 { return value; }
+    let mut x = value;
+    let mut y = value / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Integer logarithm of a positive value.
+fn ilog(mut value: u128, base: u128) -> u32 {
+    let mut log = 0;
+    while value >= base {
+        value /= base;
+        log += 1;
+    }
+    log
+}
+
+/// Greatest common divisor by Euclid's algorithm.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
@@ -0,0 +1,206 @@
+//! Overflow policies for operators.
+//!
+//! The operator implementations generated by
+//! [`bounded_integer_impls!`](../macro.bounded_integer_impls.html) and the individual operator
+//! macros take an optional `OverflowPolicy` type which decides the result of an operation that
+//! overflows.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate bounded_integer;
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! #[repr(u8)]
+//! enum TwoBit { Z0, P1, P2, P3 }
+//! bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3, bounded_integer::overflow::Wrap);
+//! # fn main() {
+//! assert_eq!(TwoBit::P1, TwoBit::P3 + TwoBit::P2);
+//! # }
+//! ```
+//!
+//! The default [`Panic`](enum.Panic.html) policy first calls the process-wide handler registered
+//! with [`set_handler`](fn.set_handler.html), which can log or count overflows, or abort with
+//! context.
+
+use core::any;
+use core::cell::UnsafeCell;
+use core::hint;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use ArithmeticError;
+use Bound;
+use Bounded;
+use Contiguous;
+use Operation;
+use Repr;
+use WrappingArith;
+use wrapping::wrap;
+
+/// Overflow policy for `T`.
+///
+/// [`Panic`](enum.Panic.html) and [`Saturate`](enum.Saturate.html) apply to every `Bounded` type,
+/// while [`Wrap`](enum.Wrap.html) and [`DebugPanic`](enum.DebugPanic.html) require a
+/// [`Contiguous`](../trait.Contiguous.html) range to wrap around.
+pub trait OverflowPolicy<T: Bounded> {
+    /// Returns the result of an operation which failed with `error`.
+    fn overflow(error: ArithmeticError) -> T;
+
+    /// Returns the result of a fallible operation, applying the policy if it failed.
+    fn resolve(result: Result<T, ArithmeticError>) -> T {
+        match result {
+            Ok(value) => value,
+            Err(error) => Self::overflow(error),
+        }
+    }
+
+    /// Returns the result of a fallible operation written in a crate compiled with or without
+    /// debug assertions, as given by `debug_assertions`.
+    ///
+    /// The operator macros pass `cfg!(debug_assertions)` as expanded in the crate which invokes
+    /// them. Defaults to [`resolve`](#method.resolve), which ignores `debug_assertions`.
+    fn resolve_debug(result: Result<T, ArithmeticError>, debug_assertions: bool) -> T {
+        let _ = debug_assertions;
+        Self::resolve(result)
+    }
+}
+
+/// Overflow handler.
+///
+/// Called with the name of the overflowing type and the error.
+pub type Handler = fn(type_name: &'static str, error: &ArithmeticError);
+
+/// Registered overflow handler, guarded by a spin lock since `core` has no mutex.
+struct HandlerCell {
+    locked: AtomicBool,
+    handler: UnsafeCell<Option<Handler>>,
+}
+
+// Access to `handler` is serialized by `locked`.
+unsafe impl Sync for HandlerCell { }
+
+impl HandlerCell {
+    /// Calls `f` with exclusive access to the handler.
+    fn with<R, F: FnOnce(&mut Option<Handler>) -> R>(&self, f: F) -> R {
+        while self.locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        let result = f(unsafe { &mut *self.handler.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+static HANDLER: HandlerCell = HandlerCell {
+    locked: AtomicBool::new(false),
+    handler: UnsafeCell::new(None),
+};
+
+/// Registers a process-wide overflow handler, replacing any previous handler.
+///
+/// The handler is called by the [`Panic`](enum.Panic.html) policy before it panics. If the handler
+/// returns, the operation still panics.
+pub fn set_handler(handler: Handler) {
+    HANDLER.with(|cell| *cell = Some(handler));
+}
+
+/// Unregisters the process-wide overflow handler.
+pub fn clear_handler() {
+    HANDLER.with(|cell| *cell = None);
+}
+
+/// Returns the registered process-wide overflow handler.
+pub fn handler() -> Option<Handler> {
+    HANDLER.with(|cell| *cell)
+}
+
+/// Panics on overflow.
+///
+/// This is the default policy. Calls the registered [`handler`](fn.handler.html) before
+/// panicking, and includes the type name in the panic message.
+#[derive(Debug, Clone, Copy)]
+pub enum Panic { }
+
+impl<T: Bounded> OverflowPolicy<T> for Panic {
+    fn overflow(error: ArithmeticError) -> T {
+        let type_name = any::type_name::<T>();
+        if let Some(handler) = handler() {
+            handler(type_name, &error);
+        }
+        panic!("arithmetic operation overflowed: {}: {}", type_name, error)
+    }
+}
+
+/// Saturates at the violated bound on overflow.
+///
+/// Division by zero, and results in a gap between the values of a type which is not
+/// [`Contiguous`](../trait.Contiguous.html), have no bound to saturate at, and are handled by the
+/// [`Panic`](enum.Panic.html) policy.
+#[derive(Debug, Clone, Copy)]
+pub enum Saturate { }
+
+impl<T: Bounded> OverflowPolicy<T> for Saturate {
+    fn overflow(error: ArithmeticError) -> T {
+        match error.bound() {
+            Some(Bound::Min(_)) => T::MIN,
+            Some(Bound::Max(_)) => T::MAX,
+            // Gaps and division by zero.
+            None => Panic::overflow(error),
+        }
+    }
+}
+
+/// Wraps around modulo the number of values in the range on overflow.
+///
+/// Division by zero is handled by the [`Panic`](enum.Panic.html) policy, as is a result in a gap,
+/// which only an incorrect `Contiguous` implementation can produce.
+#[derive(Debug, Clone, Copy)]
+pub enum Wrap { }
+
+impl<T: Contiguous> OverflowPolicy<T> for Wrap {
+    fn overflow(error: ArithmeticError) -> T {
+        if error.is_division_by_zero() || error.is_gap() {
+            return Panic::overflow(error);
+        }
+        match (error.operation(), error.result()) {
+            (_, Some(result)) => wrap(result),
+            (Operation::Mul, None) => {
+                let lhs = T::Repr::from_i128(error.lhs()).and_then(T::from_repr);
+                let rhs = error.rhs().and_then(T::Repr::from_i128);
+                match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) => lhs.wrapping_mul_repr(rhs),
+                    _ => unreachable!(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Panics on overflow when debug assertions are enabled, and wraps otherwise, like the primitive
+/// integer types.
+///
+/// Operators generated by the `bounded_integer_*_impls!` macros follow the debug assertions of the
+/// crate which invokes the macro, like primitive integers follow the crate where the operation is
+/// written. Calling [`overflow`](trait.OverflowPolicy.html#tymethod.overflow) or
+/// [`resolve`](trait.OverflowPolicy.html#method.resolve) directly follows the debug assertions
+/// `bounded-integer` itself was compiled with.
+#[derive(Debug, Clone, Copy)]
+pub enum DebugPanic { }
+
+impl<T: Contiguous> OverflowPolicy<T> for DebugPanic {
+    fn overflow(error: ArithmeticError) -> T {
+        Self::resolve_debug(Err(error), cfg!(debug_assertions))
+    }
+
+    fn resolve_debug(result: Result<T, ArithmeticError>, debug_assertions: bool) -> T {
+        match result {
+            Ok(value) => value,
+            Err(error) if debug_assertions => Panic::overflow(error),
+            Err(error) => Wrap::overflow(error),
+        }
+    }
+}
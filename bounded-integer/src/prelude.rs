@@ -0,0 +1,20 @@
+//! Re-exports the traits needed to use bounded integers.
+//!
+//! ```
+//! use bounded_integer::prelude::*;
+//! # fn main() {
+//! assert_eq!(Some(3u8), 1u8.checked_add(2));
+//! # }
+//! ```
+
+pub use Bounded;
+pub use BoundedInteger;
+pub use BoundedIterator;
+pub use ByteEncoding;
+pub use CheckedArith;
+pub use Contiguous;
+pub use FloatConv;
+pub use IntegerMath;
+pub use SaturatingArith;
+pub use UncheckedArith;
+pub use WrappingArith;
@@ -0,0 +1,58 @@
+use Bounded;
+use Contiguous;
+
+macro_rules! primitive_impl {
+    ($ty:ty) => {
+        /// Full range of the primitive type.
+        impl Bounded for $ty {
+            type Repr = $ty;
+
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const MIN_REPR: $ty = <$ty>::MIN;
+            const MAX_REPR: $ty = <$ty>::MAX;
+            const COUNT: usize = ::__count(<$ty>::MIN as i128, <$ty>::MAX as i128);
+
+            fn from_repr(repr: $ty) -> Option<Self> { Some(repr) }
+            unsafe fn from_repr_unchecked(repr: $ty) -> Self { repr }
+            fn to_repr(self) -> $ty { self }
+        }
+
+        unsafe impl Contiguous for $ty { }
+    }
+}
+
+primitive_impl!(u8);
+primitive_impl!(u16);
+primitive_impl!(u32);
+primitive_impl!(u64);
+primitive_impl!(usize);
+
+primitive_impl!(i8);
+primitive_impl!(i16);
+primitive_impl!(i32);
+primitive_impl!(i64);
+primitive_impl!(isize);
+
+/// Range `0...1`.
+impl Bounded for bool {
+    type Repr = u8;
+
+    const MIN: Self = false;
+    const MAX: Self = true;
+    const MIN_REPR: u8 = 0;
+    const MAX_REPR: u8 = 1;
+    const COUNT: usize = 2;
+
+    fn from_repr(repr: u8) -> Option<Self> {
+        match repr {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn to_repr(self) -> u8 { self as u8 }
+}
+
+unsafe impl Contiguous for bool { }
@@ -1,11 +1,43 @@
+use core::mem;
+
 /// Integer representation.
 ///
 /// Reflects the types valid in `#[repr(...)]` for C-like enums, so should not be implemented for
 /// additional types.
 pub trait Repr: Copy + Eq + Ord {
+    /// Byte array of the same size.
+    type Bytes: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// Smallest value.
+    const MIN: Self;
+
+    /// Largest value.
+    const MAX: Self;
+
+    /// Size in bits.
+    const BITS: u32;
+
+    /// True if signed.
+    const IS_SIGNED: bool;
+
     /// Returns true if negative.
     fn is_negative(self) -> bool;
 
+    /// Converts to `i128` without loss.
+    fn to_i128(self) -> i128;
+
+    /// Converts to `u128` without loss, returning `None` if negative.
+    fn to_u128(self) -> Option<u128>;
+
+    /// Checked conversion from `i128`.
+    fn from_i128(value: i128) -> Option<Self>;
+
     /// Checked integer addition.
     fn checked_add(self, other: Self) -> Option<Self>;
 
@@ -23,31 +55,149 @@ pub trait Repr: Copy + Eq + Ord {
 
     /// Checked integer negation.
     fn checked_neg(self) -> Option<Self>;
+
+    /// Wrapping integer addition.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Wrapping integer subtraction.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Wrapping integer multiplication.
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    /// Wrapping integer division.
+    fn wrapping_div(self, other: Self) -> Self;
+
+    /// Wrapping integer remainder.
+    fn wrapping_rem(self, other: Self) -> Self;
+
+    /// Wrapping integer negation.
+    fn wrapping_neg(self) -> Self;
+
+    /// Saturating integer addition.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Saturating integer subtraction.
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Saturating integer multiplication.
+    fn saturating_mul(self, other: Self) -> Self;
+
+    /// Overflowing integer addition.
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+
+    /// Overflowing integer subtraction.
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+
+    /// Overflowing integer multiplication.
+    fn overflowing_mul(self, other: Self) -> (Self, bool);
+
+    /// Overflowing integer division.
+    fn overflowing_div(self, other: Self) -> (Self, bool);
+
+    /// Overflowing integer remainder.
+    fn overflowing_rem(self, other: Self) -> (Self, bool);
+
+    /// Overflowing integer negation.
+    fn overflowing_neg(self) -> (Self, bool);
+
+    /// Returns the big-endian bytes.
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Returns the little-endian bytes.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Returns the native-endian bytes.
+    fn to_ne_bytes(self) -> Self::Bytes;
+
+    /// Converts from big-endian bytes.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Converts from little-endian bytes.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Converts from native-endian bytes.
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
 }
 
 macro_rules! repr_impl {
     ($i:ident @ $ty:ty) => {
         impl Repr for $ty {
+            type Bytes = [u8; mem::size_of::<$ty>()];
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const BITS: u32 = <$ty>::BITS;
+
             repr_impl!($i);
+
+            fn to_i128(self) -> i128 { self as i128 }
+            fn from_i128(value: i128) -> Option<Self> {
+                if value >= Self::MIN as i128 && value <= Self::MAX as i128 {
+                    Some(value as $ty)
+                } else {
+                    None
+                }
+            }
+
             fn checked_add(self, other: Self) -> Option<Self> { self.checked_add(other) }
             fn checked_sub(self, other: Self) -> Option<Self> { self.checked_sub(other) }
             fn checked_mul(self, other: Self) -> Option<Self> { self.checked_mul(other) }
             fn checked_div(self, other: Self) -> Option<Self> { self.checked_div(other) }
             fn checked_rem(self, other: Self) -> Option<Self> { self.checked_rem(other) }
             fn checked_neg(self) -> Option<Self> { self.checked_neg() }
+
+            fn wrapping_add(self, other: Self) -> Self { self.wrapping_add(other) }
+            fn wrapping_sub(self, other: Self) -> Self { self.wrapping_sub(other) }
+            fn wrapping_mul(self, other: Self) -> Self { self.wrapping_mul(other) }
+            fn wrapping_div(self, other: Self) -> Self { self.wrapping_div(other) }
+            fn wrapping_rem(self, other: Self) -> Self { self.wrapping_rem(other) }
+            fn wrapping_neg(self) -> Self { self.wrapping_neg() }
+
+            fn saturating_add(self, other: Self) -> Self { self.saturating_add(other) }
+            fn saturating_sub(self, other: Self) -> Self { self.saturating_sub(other) }
+            fn saturating_mul(self, other: Self) -> Self { self.saturating_mul(other) }
+
+            fn overflowing_add(self, other: Self) -> (Self, bool) { self.overflowing_add(other) }
+            fn overflowing_sub(self, other: Self) -> (Self, bool) { self.overflowing_sub(other) }
+            fn overflowing_mul(self, other: Self) -> (Self, bool) { self.overflowing_mul(other) }
+            fn overflowing_div(self, other: Self) -> (Self, bool) { self.overflowing_div(other) }
+            fn overflowing_rem(self, other: Self) -> (Self, bool) { self.overflowing_rem(other) }
+            fn overflowing_neg(self) -> (Self, bool) { self.overflowing_neg() }
+
+            fn to_be_bytes(self) -> Self::Bytes { self.to_be_bytes() }
+            fn to_le_bytes(self) -> Self::Bytes { self.to_le_bytes() }
+            fn to_ne_bytes(self) -> Self::Bytes { self.to_ne_bytes() }
+            fn from_be_bytes(bytes: Self::Bytes) -> Self { <$ty>::from_be_bytes(bytes) }
+            fn from_le_bytes(bytes: Self::Bytes) -> Self { <$ty>::from_le_bytes(bytes) }
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self { <$ty>::from_ne_bytes(bytes) }
         }
     };
 
-    (u) => { fn is_negative(self) -> bool { false } };
-    (i) => { fn is_negative(self) -> bool { self.is_negative() } };
+    (u) => {
+        const IS_SIGNED: bool = false;
+        fn is_negative(self) -> bool { false }
+        fn to_u128(self) -> Option<u128> { Some(self as u128) }
+    };
+    (i) => {
+        const IS_SIGNED: bool = true;
+        fn is_negative(self) -> bool { self.is_negative() }
+        fn to_u128(self) -> Option<u128> {
+            if self.is_negative() { None } else { Some(self as u128) }
+        }
+    };
 }
 
 repr_impl!(u @ u8);
 repr_impl!(u @ u16);
 repr_impl!(u @ u32);
 repr_impl!(u @ u64);
+repr_impl!(u @ usize);
 
 repr_impl!(i @ i8);
 repr_impl!(i @ i16);
 repr_impl!(i @ i32);
 repr_impl!(i @ i64);
+repr_impl!(i @ isize);
@@ -0,0 +1,129 @@
+use core::ops;
+
+use Bounded;
+use CheckedArith;
+use Repr;
+use overflow::{OverflowPolicy, Saturate};
+
+/// Saturating arithmetic.
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait SaturatingArith: CheckedArith {
+    /// Saturating integer addition.
+    fn saturating_add(self, other: Self) -> Self {
+        self.saturating_add_repr(other.to_repr())
+    }
+
+    /// Saturating integer subtraction.
+    fn saturating_sub(self, other: Self) -> Self {
+        self.saturating_sub_repr(other.to_repr())
+    }
+
+    /// Saturating integer multiplication.
+    fn saturating_mul(self, other: Self) -> Self {
+        self.saturating_mul_repr(other.to_repr())
+    }
+
+    /// Saturating integer division. Panics if `other` is zero.
+    fn saturating_div(self, other: Self) -> Self {
+        self.saturating_div_repr(other.to_repr())
+    }
+
+    /// Saturating integer remainder. Panics if `other` is zero.
+    fn saturating_rem(self, other: Self) -> Self {
+        self.saturating_rem_repr(other.to_repr())
+    }
+
+    /// Saturating integer negation.
+    fn saturating_neg(self) -> Self {
+        Saturate::resolve(self.try_neg())
+    }
+
+    // Saturating arithmetic with `Self::Repr`.
+
+    /// Saturating integer addition with `Self::Repr`.
+    fn saturating_add_repr(self, other: Self::Repr) -> Self {
+        if other.is_negative() {
+            self.checked_add_repr(other).unwrap_or(Self::min_value())
+        } else {
+            self.checked_add_repr(other).unwrap_or(Self::max_value())
+        }
+    }
+
+    /// Saturating integer subtraction with `Self::Repr`.
+    fn saturating_sub_repr(self, other: Self::Repr) -> Self {
+        if other.is_negative() {
+            self.checked_sub_repr(other).unwrap_or(Self::max_value())
+        } else {
+            self.checked_sub_repr(other).unwrap_or(Self::min_value())
+        }
+    }
+
+    /// Saturating integer multiplication with `Self::Repr`.
+    fn saturating_mul_repr(self, other: Self::Repr) -> Self {
+        if self.to_repr().is_negative() == other.is_negative() {
+            self.checked_mul_repr(other).unwrap_or(Self::max_value())
+        } else {
+            self.checked_mul_repr(other).unwrap_or(Self::min_value())
+        }
+    }
+
+    /// Saturating integer division with `Self::Repr`. Panics if `other` is zero.
+    fn saturating_div_repr(self, other: Self::Repr) -> Self {
+        Saturate::resolve(self.try_div_repr(other))
+    }
+
+    /// Saturating integer remainder with `Self::Repr`. Panics if `other` is zero.
+    fn saturating_rem_repr(self, other: Self::Repr) -> Self {
+        Saturate::resolve(self.try_rem_repr(other))
+    }
+}
+
+impl<T: Bounded> SaturatingArith for T { }
+
+/// Provides intentionally-saturated arithmetic on `T`.
+///
+/// Operators on `Saturating<T>` use the saturating arithmetic of
+/// [`SaturatingArith`](trait.SaturatingArith.html). Division and remainder by zero panic, like
+/// `std::num::Saturating`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bounded_integer;
+/// # use bounded_integer::Saturating;
+/// # fn main() {
+/// let x = Saturating(200u8);
+/// assert_eq!(Saturating(255), x + x);
+/// assert_eq!(Saturating(0), x - x - x);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+impl<T> From<T> for Saturating<T> {
+    fn from(value: T) -> Self { Saturating(value) }
+}
+
+macro_rules! saturating_binop_impl {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $saturating_fn:ident) => {
+        impl<T: Bounded> ops::$op for Saturating<T> {
+            type Output = Self;
+            fn $op_fn(self, rhs: Self) -> Self { Saturating(self.0.$saturating_fn(rhs.0)) }
+        }
+        impl<T: Bounded> ops::$assign for Saturating<T> {
+            fn $assign_fn(&mut self, rhs: Self) { *self = ops::$op::$op_fn(*self, rhs); }
+        }
+    }
+}
+
+saturating_binop_impl!(Add, add, AddAssign, add_assign, saturating_add);
+saturating_binop_impl!(Sub, sub, SubAssign, sub_assign, saturating_sub);
+saturating_binop_impl!(Mul, mul, MulAssign, mul_assign, saturating_mul);
+saturating_binop_impl!(Div, div, DivAssign, div_assign, saturating_div);
+saturating_binop_impl!(Rem, rem, RemAssign, rem_assign, saturating_rem);
+
+impl<T: Bounded> ops::Neg for Saturating<T> {
+    type Output = Self;
+    fn neg(self) -> Self { Saturating(self.0.saturating_neg()) }
+}
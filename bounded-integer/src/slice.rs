@@ -0,0 +1,188 @@
+//! Arithmetic on slices of bounded integers.
+//!
+//! Values are processed as `Repr` in batches, with a single range check per batch rather than per
+//! value, so the loops can be auto-vectorized.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate bounded_integer;
+//! use bounded_integer::slice;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! #[repr(u8)]
+//! enum TwoBit { Z0, P1, P2, P3 }
+//! bounded_integer_impls!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+//! # fn main() {
+//! let mut levels = [TwoBit::Z0, TwoBit::P2, TwoBit::P3];
+//! slice::saturating_add_assign_slice(&mut levels, 1);
+//! assert_eq!([TwoBit::P1, TwoBit::P3, TwoBit::P3], levels);
+//! # }
+//! ```
+
+use Contiguous;
+use Repr;
+use WrappingArith;
+use contiguous::CHUNK;
+
+/// Checked addition of two slices into a third.
+///
+/// Returns the index of the first sum out of range on failure, leaving `out` partially written.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn checked_add_slices<T: Contiguous>(lhs: &[T], rhs: &[T], out: &mut [T]) -> Result<(), usize> {
+    checked_slices(lhs, rhs, out, Repr::overflowing_add)
+}
+
+/// Checked subtraction of two slices into a third.
+///
+/// Returns the index of the first difference out of range on failure, leaving `out` partially
+/// written.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn checked_sub_slices<T: Contiguous>(lhs: &[T], rhs: &[T], out: &mut [T]) -> Result<(), usize> {
+    checked_slices(lhs, rhs, out, Repr::overflowing_sub)
+}
+
+/// Checked multiplication of two slices into a third.
+///
+/// Returns the index of the first product out of range on failure, leaving `out` partially
+/// written.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn checked_mul_slices<T: Contiguous>(lhs: &[T], rhs: &[T], out: &mut [T]) -> Result<(), usize> {
+    checked_slices(lhs, rhs, out, Repr::overflowing_mul)
+}
+
+/// Saturating addition of `rhs` to every value.
+pub fn saturating_add_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    saturating_assign(values, rhs, Repr::saturating_add)
+}
+
+/// Saturating subtraction of `rhs` from every value.
+pub fn saturating_sub_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    saturating_assign(values, rhs, Repr::saturating_sub)
+}
+
+/// Saturating multiplication of every value by `rhs`.
+pub fn saturating_mul_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    saturating_assign(values, rhs, Repr::saturating_mul)
+}
+
+/// Wrapping addition of `rhs` to every value.
+///
+/// Wraps modulo the number of values in the range of `T`, like
+/// [`WrappingArith`](../trait.WrappingArith.html).
+pub fn wrapping_add_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    wrapping_assign(values, rhs, Repr::overflowing_add, WrappingArith::wrapping_add_repr)
+}
+
+/// Wrapping subtraction of `rhs` from every value.
+///
+/// Wraps modulo the number of values in the range of `T`, like
+/// [`WrappingArith`](../trait.WrappingArith.html).
+pub fn wrapping_sub_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    wrapping_assign(values, rhs, Repr::overflowing_sub, WrappingArith::wrapping_sub_repr)
+}
+
+/// Wrapping multiplication of every value by `rhs`.
+///
+/// Wraps modulo the number of values in the range of `T`, like
+/// [`WrappingArith`](../trait.WrappingArith.html).
+pub fn wrapping_mul_assign_slice<T: Contiguous>(values: &mut [T], rhs: T::Repr) {
+    wrapping_assign(values, rhs, Repr::overflowing_mul, WrappingArith::wrapping_mul_repr)
+}
+
+/// Returns true if `repr` is in the range of `T`, without branching.
+fn in_range<T: Contiguous>(repr: T::Repr) -> bool {
+    (repr >= T::MIN_REPR) & (repr <= T::MAX_REPR)
+}
+
+/// Applies an overflowing operation to pairs of values, writing each batch to `out` only if every
+/// result in it is in range.
+fn checked_slices<T, F>(lhs: &[T], rhs: &[T], out: &mut [T], op: F) -> Result<(), usize>
+where
+    T: Contiguous,
+    F: Fn(T::Repr, T::Repr) -> (T::Repr, bool),
+{
+    assert_eq!(lhs.len(), rhs.len(), "slice lengths differ");
+    assert_eq!(lhs.len(), out.len(), "slice lengths differ");
+
+    let (lhs, rhs) = (T::as_repr_slice(lhs), T::as_repr_slice(rhs));
+    // Only batches of results in range are written.
+    let out = unsafe { T::as_repr_slice_mut(out) };
+    let mut buffer = [T::Repr::ZERO; CHUNK];
+
+    let chunks = lhs.chunks(CHUNK).zip(rhs.chunks(CHUNK)).zip(out.chunks_mut(CHUNK));
+    for (i, ((lhs, rhs), out)) in chunks.enumerate() {
+        let buffer = &mut buffer[..lhs.len()];
+        let mut valid = true;
+        for ((result, &lhs), &rhs) in buffer.iter_mut().zip(lhs).zip(rhs) {
+            let (value, overflow) = op(lhs, rhs);
+            *result = value;
+            valid &= !overflow & in_range::<T>(value);
+        }
+        if !valid {
+            let position = lhs.iter().zip(rhs)
+                .position(|(&lhs, &rhs)| {
+                    let (value, overflow) = op(lhs, rhs);
+                    overflow || !in_range::<T>(value)
+                })
+                .unwrap();
+            return Err(i * CHUNK + position);
+        }
+        out.copy_from_slice(buffer);
+    }
+    Ok(())
+}
+
+/// Applies a saturating operation to every value, then clamps it to the range of `T`.
+fn saturating_assign<T, F>(values: &mut [T], rhs: T::Repr, op: F)
+where
+    T: Contiguous,
+    F: Fn(T::Repr, T::Repr) -> T::Repr,
+{
+    // Every value is clamped into range.
+    let values = unsafe { T::as_repr_slice_mut(values) };
+    for value in values {
+        *value = op(*value, rhs).clamp(T::MIN_REPR, T::MAX_REPR);
+    }
+}
+
+/// Applies an overflowing operation to every value, falling back to `wrap` for batches with
+/// results out of range.
+fn wrapping_assign<T, F, W>(values: &mut [T], rhs: T::Repr, op: F, wrap: W)
+where
+    T: Contiguous,
+    F: Fn(T::Repr, T::Repr) -> (T::Repr, bool),
+    W: Fn(T, T::Repr) -> T,
+{
+    // Wrapping in `Repr` is wrapping in `T` if the range is all of `Repr`.
+    let full = T::MIN_REPR == T::Repr::MIN && T::MAX_REPR == T::Repr::MAX;
+    let mut buffer = [T::Repr::ZERO; CHUNK];
+
+    for values in values.chunks_mut(CHUNK) {
+        let buffer = &mut buffer[..values.len()];
+        let mut valid = true;
+        for (result, &value) in buffer.iter_mut().zip(T::as_repr_slice(values)) {
+            let (value, overflow) = op(value, rhs);
+            *result = value;
+            valid &= (full | !overflow) & in_range::<T>(value);
+        }
+        if valid {
+            // Every result in the batch is in range.
+            unsafe { T::as_repr_slice_mut(values) }.copy_from_slice(buffer);
+        } else {
+            for value in values {
+                *value = wrap(*value, rhs);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use Bounded;
+use CheckedArith;
+use Repr;
+
+/// Unchecked arithmetic.
+///
+/// Results are only checked with `debug_assert!`, for use where the operands have already been
+/// validated.
+///
+/// Implemented for all [`Bounded`](trait.Bounded.html) types.
+pub trait UncheckedArith: CheckedArith {
+    /// Unchecked integer addition.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_add(other)` must return `Some`.
+    unsafe fn unchecked_add(self, other: Self) -> Self {
+        self.unchecked_add_repr(other.to_repr())
+    }
+
+    /// Unchecked integer subtraction.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_sub(other)` must return `Some`.
+    unsafe fn unchecked_sub(self, other: Self) -> Self {
+        self.unchecked_sub_repr(other.to_repr())
+    }
+
+    /// Unchecked integer multiplication.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_mul(other)` must return `Some`.
+    unsafe fn unchecked_mul(self, other: Self) -> Self {
+        self.unchecked_mul_repr(other.to_repr())
+    }
+
+    // Unchecked arithmetic with `Self::Repr`.
+
+    /// Unchecked integer addition with `Self::Repr`.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_add_repr(other)` must return `Some`.
+    unsafe fn unchecked_add_repr(self, other: Self::Repr) -> Self {
+        debug_assert!(self.checked_add_repr(other).is_some(), "arithmetic operation overflowed");
+        Self::from_repr_unchecked(self.to_repr().wrapping_add(other))
+    }
+
+    /// Unchecked integer subtraction with `Self::Repr`.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_sub_repr(other)` must return `Some`.
+    unsafe fn unchecked_sub_repr(self, other: Self::Repr) -> Self {
+        debug_assert!(self.checked_sub_repr(other).is_some(), "arithmetic operation overflowed");
+        Self::from_repr_unchecked(self.to_repr().wrapping_sub(other))
+    }
+
+    /// Unchecked integer multiplication with `Self::Repr`.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_mul_repr(other)` must return `Some`.
+    unsafe fn unchecked_mul_repr(self, other: Self::Repr) -> Self {
+        debug_assert!(self.checked_mul_repr(other).is_some(), "arithmetic operation overflowed");
+        Self::from_repr_unchecked(self.to_repr().wrapping_mul(other))
+    }
+}
+
+impl<T: Bounded> UncheckedArith for T { }
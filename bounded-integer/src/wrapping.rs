@@ -0,0 +1,138 @@
+use core::ops;
+
+use CheckedArith;
+use Contiguous;
+use Repr;
+use overflow::{OverflowPolicy, Wrap};
+
+/// Wrapping arithmetic.
+///
+/// Results wrap around modulo the number of values in the range, so for example adding one to the
+/// maximum value gives the minimum value.
+///
+/// Implemented for all [`Contiguous`](trait.Contiguous.html) types. A `Bounded` type with gaps in
+/// its range has no wrapped value for results which land in a gap, so it does not implement
+/// `WrappingArith`.
+pub trait WrappingArith: Contiguous {
+    /// Wrapping integer addition.
+    fn wrapping_add(self, other: Self) -> Self {
+        self.wrapping_add_repr(other.to_repr())
+    }
+
+    /// Wrapping integer subtraction.
+    fn wrapping_sub(self, other: Self) -> Self {
+        self.wrapping_sub_repr(other.to_repr())
+    }
+
+    /// Wrapping integer multiplication.
+    fn wrapping_mul(self, other: Self) -> Self {
+        self.wrapping_mul_repr(other.to_repr())
+    }
+
+    /// Wrapping integer division. Panics if `other` is zero.
+    fn wrapping_div(self, other: Self) -> Self {
+        self.wrapping_div_repr(other.to_repr())
+    }
+
+    /// Wrapping integer remainder. Panics if `other` is zero.
+    fn wrapping_rem(self, other: Self) -> Self {
+        self.wrapping_rem_repr(other.to_repr())
+    }
+
+    /// Wrapping integer negation.
+    fn wrapping_neg(self) -> Self {
+        wrap(-self.to_repr().to_i128())
+    }
+
+    // Wrapping arithmetic with `Self::Repr`.
+
+    /// Wrapping integer addition with `Self::Repr`.
+    fn wrapping_add_repr(self, other: Self::Repr) -> Self {
+        wrap(self.to_repr().to_i128() + other.to_i128())
+    }
+
+    /// Wrapping integer subtraction with `Self::Repr`.
+    fn wrapping_sub_repr(self, other: Self::Repr) -> Self {
+        wrap(self.to_repr().to_i128() - other.to_i128())
+    }
+
+    /// Wrapping integer multiplication with `Self::Repr`.
+    fn wrapping_mul_repr(self, other: Self::Repr) -> Self {
+        // The product of two 64-bit values can overflow `i128`, so multiply the residues instead.
+        let count = count::<Self>();
+        let lhs = self.to_repr().to_i128().rem_euclid(count) as u128;
+        let rhs = other.to_i128().rem_euclid(count) as u128;
+        wrap((lhs * rhs % count as u128) as i128)
+    }
+
+    /// Wrapping integer division with `Self::Repr`. Panics if `other` is zero.
+    fn wrapping_div_repr(self, other: Self::Repr) -> Self {
+        Wrap::resolve(self.try_div_repr(other))
+    }
+
+    /// Wrapping integer remainder with `Self::Repr`. Panics if `other` is zero.
+    fn wrapping_rem_repr(self, other: Self::Repr) -> Self {
+        Wrap::resolve(self.try_rem_repr(other))
+    }
+}
+
+impl<T: Contiguous> WrappingArith for T { }
+
+/// Returns the number of values in the range of `T`.
+fn count<T: Contiguous>() -> i128 {
+    T::MAX_REPR.to_i128() - T::MIN_REPR.to_i128() + 1
+}
+
+/// Maps a value into the range of `T` modulo the number of values in the range.
+pub(crate) fn wrap<T: Contiguous>(value: i128) -> T {
+    let min = T::MIN_REPR.to_i128();
+    let value = min + (value - min).rem_euclid(count::<T>());
+    T::Repr::from_i128(value).and_then(T::from_repr).expect("wrapped value not in range")
+}
+
+/// Provides intentionally-wrapped arithmetic on `T`.
+///
+/// Operators on `Wrapping<T>` use the wrapping arithmetic of
+/// [`WrappingArith`](trait.WrappingArith.html), which wraps modulo the number of values in the
+/// range of `T`. Division and remainder by zero panic, like `std::num::Wrapping`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bounded_integer;
+/// # use bounded_integer::Wrapping;
+/// # fn main() {
+/// let x = Wrapping(200u8);
+/// assert_eq!(Wrapping(144), x + x);
+/// assert_eq!(Wrapping(56), -x);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping<T>(pub T);
+
+impl<T> From<T> for Wrapping<T> {
+    fn from(value: T) -> Self { Wrapping(value) }
+}
+
+macro_rules! wrapping_binop_impl {
+    ($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $wrapping_fn:ident) => {
+        impl<T: Contiguous> ops::$op for Wrapping<T> {
+            type Output = Self;
+            fn $op_fn(self, rhs: Self) -> Self { Wrapping(self.0.$wrapping_fn(rhs.0)) }
+        }
+        impl<T: Contiguous> ops::$assign for Wrapping<T> {
+            fn $assign_fn(&mut self, rhs: Self) { *self = ops::$op::$op_fn(*self, rhs); }
+        }
+    }
+}
+
+wrapping_binop_impl!(Add, add, AddAssign, add_assign, wrapping_add);
+wrapping_binop_impl!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
+wrapping_binop_impl!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
+wrapping_binop_impl!(Div, div, DivAssign, div_assign, wrapping_div);
+wrapping_binop_impl!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
+
+impl<T: Contiguous> ops::Neg for Wrapping<T> {
+    type Output = Self;
+    fn neg(self) -> Self { Wrapping(self.0.wrapping_neg()) }
+}
@@ -1,7 +1,7 @@
 #[macro_use]
 extern crate bounded_integer;
 
-use bounded_integer::BoundedInteger;
+use bounded_integer::prelude::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate bounded_integer;
+
+#[cfg(feature = "std")]
+use std::io::{Cursor, ErrorKind};
+
+use bounded_integer::ByteEncoding;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u16)]
+enum Port {
+    P1000 = 1000, P1001, P1002, P1003
+}
+bounded_integer_impls!(Port, u16, Port::P1000, Port::P1003);
+
+#[test]
+fn to_bytes() {
+    assert_eq!([0x03, 0xe9], Port::P1001.to_be_bytes());
+    assert_eq!([0xe9, 0x03], Port::P1001.to_le_bytes());
+    assert_eq!(1001u16.to_ne_bytes(), Port::P1001.to_ne_bytes());
+}
+
+#[test]
+fn from_bytes() {
+    assert_eq!(Ok(Port::P1002), Port::from_be_bytes([0x03, 0xea]));
+    assert_eq!(Ok(Port::P1002), Port::from_le_bytes([0xea, 0x03]));
+    assert_eq!(Ok(Port::P1002), Port::from_ne_bytes(1002u16.to_ne_bytes()));
+}
+
+#[test]
+fn from_bytes_out_of_range() {
+    let error = Port::from_be_bytes([0x03, 0xe7]).unwrap_err();
+    assert_eq!(1000, error.min());
+    assert_eq!(1003, error.max());
+    assert!(Port::from_le_bytes([0x03, 0xe8]).is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn read() {
+    let mut reader = Cursor::new(vec![0x03, 0xe8, 0xeb, 0x03]);
+    assert_eq!(Port::P1000, Port::read_be_from(&mut reader).unwrap());
+    assert_eq!(Port::P1003, Port::read_le_from(&mut reader).unwrap());
+    let error = Port::read_be_from(&mut reader).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn read_out_of_range() {
+    let mut reader = Cursor::new(vec![0x00, 0x00]);
+    let error = Port::read_be_from(&mut reader).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write() {
+    let mut writer = Vec::new();
+    Port::P1001.write_be_to(&mut writer).unwrap();
+    Port::P1001.write_le_to(&mut writer).unwrap();
+    assert_eq!(vec![0x03, 0xe9, 0xe9, 0x03], writer);
+}
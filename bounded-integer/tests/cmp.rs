@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate bounded_integer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+bounded_integer_cmp_repr_impls!(SNibble, i8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Byte {
+    P254 = 254, P255
+}
+bounded_integer_impls!(Byte, u8, Byte::P254, Byte::P255);
+bounded_integer_cmp_repr_impls!(Byte, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum Low {
+    N2 = -2, N1
+}
+bounded_integer_impls!(Low, i8, Low::N2, Low::N1);
+
+bounded_integer_cmp_impls!(SNibble, Byte);
+bounded_integer_cmp_impls!(Low, Byte);
+bounded_integer_cmp_impls!(SNibble, Low);
+
+#[test]
+fn eq_repr() {
+    assert!(SNibble::P3 == 3);
+    assert!(SNibble::N8 != 7);
+    assert!(-8 == SNibble::N8);
+    assert!(Byte::P255 == 255);
+}
+
+#[test]
+fn ord_repr() {
+    assert!(SNibble::P3 < 4);
+    assert!(SNibble::N1 >= -1);
+    assert!(0 > SNibble::N1);
+    assert!(254 <= Byte::P254);
+}
+
+#[test]
+fn eq_cross() {
+    assert!(SNibble::N2 == Low::N2);
+    assert!(Low::N1 == SNibble::N1);
+    assert!(SNibble::N1 != Byte::P255);
+    assert!(Byte::P255 != Low::N1);
+}
+
+#[test]
+fn ord_cross() {
+    assert!(SNibble::P7 < Byte::P254);
+    assert!(Byte::P254 > SNibble::N1);
+    assert!(Low::N1 < Byte::P255);
+    assert!(Low::N2 < SNibble::Z0);
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::Contiguous;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+#[test]
+fn from_repr_slice() {
+    let reprs = [-8, 0, 7];
+    let values = SNibble::from_repr_slice(&reprs).unwrap();
+    assert_eq!(&[SNibble::N8, SNibble::Z0, SNibble::P7], values);
+    assert_eq!(Ok(&[][..]), SNibble::from_repr_slice(&[]));
+}
+
+#[test]
+fn from_repr_slice_invalid() {
+    assert_eq!(Err(0), SNibble::from_repr_slice(&[8]));
+    assert_eq!(Err(2), SNibble::from_repr_slice(&[0, 1, -9, 8]));
+}
+
+#[test]
+fn from_repr_slice_invalid_in_later_chunk() {
+    let mut reprs = vec![3i8; 1000];
+    reprs[700] = 100;
+    reprs[900] = 100;
+    assert_eq!(Err(700), SNibble::from_repr_slice(&reprs));
+    reprs[700] = 3;
+    reprs[900] = 3;
+    assert_eq!(1000, SNibble::from_repr_slice(&reprs).unwrap().len());
+}
+
+#[test]
+fn from_repr_slice_mut() {
+    let mut reprs = [1, 2, 3];
+    {
+        let values = SNibble::from_repr_slice_mut(&mut reprs).unwrap();
+        values[1] = SNibble::N5;
+    }
+    assert_eq!([1, -5, 3], reprs);
+    assert_eq!(Err(1), SNibble::from_repr_slice_mut(&mut [0, -100]));
+}
+
+#[test]
+fn as_repr_slice() {
+    let values = [SNibble::N1, SNibble::P6];
+    assert_eq!(&[-1, 6], SNibble::as_repr_slice(&values));
+}
+
+#[test]
+fn as_repr_slice_mut() {
+    let mut values = [SNibble::N1, SNibble::P6];
+    unsafe { SNibble::as_repr_slice_mut(&mut values)[0] = 4; }
+    assert_eq!([SNibble::P4, SNibble::P6], values);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn vec() {
+    let values = SNibble::from_repr_vec(vec![-2, 5]).unwrap();
+    assert_eq!(vec![SNibble::N2, SNibble::P5], values);
+    assert_eq!(vec![-2, 5], SNibble::into_repr_vec(values));
+    assert_eq!(Err(1), SNibble::from_repr_vec(vec![-2, 50]));
+}
+
+#[test]
+fn bool_slice() {
+    assert_eq!(Ok(&[true, false][..]), bool::from_repr_slice(&[1, 0]));
+    assert_eq!(Err(0), bool::from_repr_slice(&[2, 0]));
+}
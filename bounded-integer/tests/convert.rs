@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use std::convert::TryFrom;
+
+use bounded_integer::RangeError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum UNibble {
+    Z0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15
+}
+bounded_integer_impls!(UNibble, i8, UNibble::Z0, UNibble::P15);
+bounded_integer_from_impls!(UNibble, u8, u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble { N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7 }
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+bounded_integer_from_impls!(SNibble, i16, i64, f64);
+
+#[test]
+fn from_unsigned_range() {
+    assert_eq!(15u8, u8::from(UNibble::P15));
+    assert_eq!(0u128, u128::from(UNibble::Z0));
+    assert_eq!(7i16, i16::from(UNibble::P7));
+    assert_eq!(12.0f32, f32::from(UNibble::P12));
+}
+
+#[test]
+fn from_signed_range() {
+    assert_eq!(-8i16, i16::from(SNibble::N8));
+    assert_eq!(7i64, SNibble::P7.into());
+    assert_eq!(-1.0f64, f64::from(SNibble::N1));
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(Ok(UNibble::P15), UNibble::try_from(15u64));
+    assert_eq!(Ok(UNibble::Z0), UNibble::try_from(0i128));
+    assert_eq!(Ok(SNibble::N8), SNibble::try_from(-8isize));
+    assert_eq!(Ok(SNibble::P7), SNibble::try_from(7usize));
+}
+
+#[test]
+fn try_from_out_of_range() {
+    assert!(UNibble::try_from(16u8).is_err());
+    assert!(UNibble::try_from(-1i8).is_err());
+    assert!(SNibble::try_from(-9i32).is_err());
+    assert!(SNibble::try_from(256u16).is_err());
+    assert!(SNibble::try_from(u128::MAX).is_err());
+    assert!(SNibble::try_from(i128::MIN).is_err());
+}
+
+#[test]
+fn range_error() {
+    let error: RangeError = SNibble::try_from(8u8).unwrap_err();
+    assert_eq!(-8, error.min());
+    assert_eq!(7, error.max());
+    assert_eq!("value is outside the range -8...7", error.to_string());
+}
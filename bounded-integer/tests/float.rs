@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::{FloatConv, OutOfRange, Rounding};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Level {
+    P4 = 4, P5, P6, P7, P8
+}
+bounded_integer_impls!(Level, u8, Level::P4, Level::P8);
+
+fn reject(value: f64, rounding: Rounding) -> Option<SNibble> {
+    SNibble::from_f64(value, rounding, OutOfRange::Reject).ok()
+}
+
+#[test]
+fn floor() {
+    assert_eq!(Some(SNibble::P2), reject(2.7, Rounding::Floor));
+    assert_eq!(Some(SNibble::N3), reject(-2.2, Rounding::Floor));
+    assert_eq!(Some(SNibble::N2), reject(-2.0, Rounding::Floor));
+}
+
+#[test]
+fn ceil() {
+    assert_eq!(Some(SNibble::P3), reject(2.2, Rounding::Ceil));
+    assert_eq!(Some(SNibble::N2), reject(-2.7, Rounding::Ceil));
+    assert_eq!(Some(SNibble::P2), reject(2.0, Rounding::Ceil));
+}
+
+#[test]
+fn nearest_even() {
+    assert_eq!(Some(SNibble::P2), reject(2.5, Rounding::NearestEven));
+    assert_eq!(Some(SNibble::P4), reject(3.5, Rounding::NearestEven));
+    assert_eq!(Some(SNibble::N2), reject(-2.5, Rounding::NearestEven));
+    assert_eq!(Some(SNibble::N4), reject(-3.5, Rounding::NearestEven));
+    assert_eq!(Some(SNibble::P3), reject(2.6, Rounding::NearestEven));
+    assert_eq!(Some(SNibble::N1), reject(-0.6, Rounding::NearestEven));
+}
+
+#[test]
+fn truncate() {
+    assert_eq!(Some(SNibble::P2), reject(2.7, Rounding::Truncate));
+    assert_eq!(Some(SNibble::N2), reject(-2.7, Rounding::Truncate));
+    assert_eq!(Some(SNibble::P7), reject(7.9, Rounding::Truncate));
+}
+
+#[test]
+fn reject_out_of_range() {
+    assert_eq!(None, reject(7.9, Rounding::Ceil));
+    assert_eq!(None, reject(-8.5, Rounding::Floor));
+    assert_eq!(None, reject(1e300, Rounding::Truncate));
+    assert_eq!(None, reject(f64::NEG_INFINITY, Rounding::Truncate));
+}
+
+#[test]
+fn clamp_out_of_range() {
+    let clamp = |value| SNibble::from_f64(value, Rounding::Floor, OutOfRange::Clamp);
+    assert_eq!(Ok(SNibble::P7), clamp(100.0));
+    assert_eq!(Ok(SNibble::N8), clamp(-8.5));
+    assert_eq!(Ok(SNibble::P7), clamp(f64::INFINITY));
+    assert_eq!(Ok(SNibble::N8), clamp(f64::NEG_INFINITY));
+}
+
+#[test]
+fn clamp_positive_range() {
+    let clamp = |value| Level::from_f64(value, Rounding::NearestEven, OutOfRange::Clamp);
+    assert_eq!(Ok(Level::P4), clamp(1.0));
+    assert_eq!(Ok(Level::P4), clamp(-1.0));
+    assert_eq!(Ok(Level::P8), clamp(9.0));
+    assert_eq!(Ok(Level::P6), clamp(6.4));
+}
+
+#[test]
+fn nan() {
+    assert!(SNibble::from_f64(f64::NAN, Rounding::Floor, OutOfRange::Clamp).is_err());
+    assert!(SNibble::from_f32(f32::NAN, Rounding::Floor, OutOfRange::Reject).is_err());
+}
+
+#[test]
+fn from_f32() {
+    assert_eq!(Ok(SNibble::N1), SNibble::from_f32(-0.5, Rounding::Floor, OutOfRange::Reject));
+}
+
+#[test]
+fn primitive() {
+    let big = u64::from_f64(1e19, Rounding::Floor, OutOfRange::Reject);
+    assert_eq!(Ok(10_000_000_000_000_000_000), big);
+    assert_eq!(Ok(u64::MAX), u64::from_f64(1e20, Rounding::Floor, OutOfRange::Clamp));
+    assert_eq!(Ok(0), u64::from_f64(-0.5, Rounding::Ceil, OutOfRange::Reject));
+    assert_eq!(Ok(0), u64::from_f64(-1.5, Rounding::Ceil, OutOfRange::Clamp));
+}
+
+#[test]
+fn to_float() {
+    assert_eq!(-8.0, SNibble::N8.to_f64());
+    assert_eq!(7.0, SNibble::P7.to_f32());
+}
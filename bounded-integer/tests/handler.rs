@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bounded_integer::{ArithmeticError, Operation};
+use bounded_integer::overflow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Bit { Z0, P1 }
+bounded_integer_impls!(Bit, u8, Bit::Z0, Bit::P1);
+
+static OVERFLOWS: AtomicUsize = AtomicUsize::new(0);
+
+fn count(type_name: &'static str, error: &ArithmeticError) {
+    assert!(type_name.ends_with("Bit"));
+    assert_eq!(Operation::Add, error.operation());
+    assert_eq!(1, error.lhs());
+    assert_eq!(Some(1), error.rhs());
+    let _ = OVERFLOWS.fetch_add(1, Ordering::SeqCst);
+}
+
+// Handlers are process-wide, so this is the only test in this file.
+#[test]
+fn handler() {
+    assert!(overflow::handler().is_none());
+    overflow::set_handler(count);
+    assert!(overflow::handler().is_some());
+
+    let result = panic::catch_unwind(|| Bit::P1 + Bit::P1);
+    assert_eq!(1, OVERFLOWS.load(Ordering::SeqCst));
+
+    let payload = result.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("Bit: 1 + 1 = 2 is above maximum 1"));
+
+    overflow::clear_handler();
+    assert!(panic::catch_unwind(|| Bit::P1 + Bit::P1).is_err());
+    assert_eq!(1, OVERFLOWS.load(Ordering::SeqCst));
+}
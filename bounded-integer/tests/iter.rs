@@ -0,0 +1,115 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::overflow::Saturate;
+use bounded_integer::{BoundedIterator, Operation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Period {
+    P4 = 4, P5, P6, P7, P8, P9, P10, P11, P12
+}
+bounded_integer_impls!(Period, u8, Period::P4, Period::P12, Saturate);
+
+/// Panics on overflow in operators, but saturates in sums and products.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum TwoBit { Z0, P1, P2, P3 }
+bounded_integer_impl!(TwoBit, u8, TwoBit::Z0, TwoBit::P3);
+bounded_integer_add_self_impls!(TwoBit);
+bounded_integer_mul_self_impls!(TwoBit);
+bounded_integer_sum_impls!(TwoBit, Saturate);
+
+#[test]
+fn sum() {
+    let values = vec![SNibble::P3, SNibble::N5, SNibble::P2];
+    assert_eq!(SNibble::Z0, values.iter().sum());
+    assert_eq!(SNibble::Z0, values.into_iter().sum());
+    assert_eq!(SNibble::Z0, Vec::<SNibble>::new().into_iter().sum());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflowed")]
+fn sum_overflow() {
+    let _: SNibble = vec![SNibble::P7, SNibble::P1, SNibble::N3].into_iter().sum();
+}
+
+#[test]
+fn product() {
+    let values = [SNibble::N2, SNibble::P3, SNibble::N1];
+    assert_eq!(SNibble::P6, values.iter().product());
+    assert_eq!(SNibble::P1, Vec::<SNibble>::new().into_iter().product());
+}
+
+#[test]
+fn sum_policy() {
+    let values = [Period::P8, Period::P9];
+    assert_eq!(Period::P12, values.iter().sum());
+    assert_eq!(Period::P4, Vec::<Period>::new().into_iter().sum());
+    assert_eq!(Period::P4, Vec::<Period>::new().into_iter().product());
+}
+
+#[test]
+fn sum_policy_overrides_operators() {
+    assert_eq!(TwoBit::P3, [TwoBit::P2, TwoBit::P3, TwoBit::Z0].iter().sum());
+    assert_eq!(TwoBit::P3, [TwoBit::P2, TwoBit::P3].iter().product());
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflowed")]
+fn sum_policy_operators_overflow() {
+    let _ = TwoBit::P2 + TwoBit::P3;
+}
+
+#[test]
+fn checked_sum() {
+    let values = [SNibble::P3, SNibble::P4];
+    assert_eq!(Some(SNibble::P7), values.iter().cloned().checked_sum());
+    assert_eq!(None, values.iter().cloned().chain(Some(SNibble::P1)).checked_sum());
+    assert_eq!(Some(SNibble::Z0), Vec::<SNibble>::new().into_iter().checked_sum());
+    assert_eq!(None, Vec::<Period>::new().into_iter().checked_sum());
+    assert_eq!(Some(10u8), vec![1u8, 2, 3, 4].into_iter().checked_sum());
+}
+
+#[test]
+fn saturating_sum() {
+    let values = vec![SNibble::P7, SNibble::P7, SNibble::N3];
+    assert_eq!(SNibble::P4, values.into_iter().saturating_sum());
+    assert_eq!(SNibble::Z0, Vec::<SNibble>::new().into_iter().saturating_sum());
+    assert_eq!(Period::P4, Vec::<Period>::new().into_iter().saturating_sum());
+}
+
+#[test]
+fn checked_product() {
+    let values = vec![Period::P4, Period::P5];
+    assert_eq!(None, values.into_iter().checked_product());
+    assert_eq!(Some(Period::P12), vec![Period::P12].into_iter().checked_product());
+    assert_eq!(None, Vec::<Period>::new().into_iter().checked_product());
+}
+
+#[test]
+fn try_sum() {
+    let values = vec![SNibble::P3, SNibble::P4, SNibble::P2];
+    let error = values.into_iter().try_sum().unwrap_err();
+    assert_eq!(Operation::Add, error.operation());
+    assert_eq!("7 + 2 = 9 is above maximum 7", error.to_string());
+
+    let error = Vec::<Period>::new().into_iter().try_sum().unwrap_err();
+    assert_eq!("0 + 0 = 0 is below minimum 4", error.to_string());
+}
+
+#[test]
+fn try_product() {
+    let error = vec![SNibble::N4, SNibble::P3].into_iter().try_product().unwrap_err();
+    assert_eq!("-4 * 3 = -12 is below minimum -8", error.to_string());
+}
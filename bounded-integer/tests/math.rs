@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::{Bounded, IntegerMath};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Period {
+    P4 = 4, P5, P6, P7, P8, P9, P10, P11, P12
+}
+bounded_integer_impls!(Period, u8, Period::P4, Period::P12);
+
+#[test]
+fn checked_isqrt() {
+    assert_eq!(Some(SNibble::Z0), SNibble::Z0.checked_isqrt());
+    assert_eq!(Some(SNibble::P1), SNibble::P3.checked_isqrt());
+    assert_eq!(Some(SNibble::P2), SNibble::P4.checked_isqrt());
+    assert_eq!(None, SNibble::N1.checked_isqrt());
+    assert_eq!(None, Period::P9.checked_isqrt());
+    assert_eq!(Some(4294967295), u64::MAX.checked_isqrt());
+}
+
+#[test]
+fn checked_isqrt_brute_force() {
+    for value in 0..=u8::MAX {
+        let root = (0..=value)
+            .take_while(|&root| u16::from(root) * u16::from(root) <= u16::from(value))
+            .last();
+        assert_eq!(root, value.checked_isqrt(), "isqrt({})", value);
+    }
+}
+
+#[test]
+fn ilog() {
+    assert_eq!(0, SNibble::P1.ilog2());
+    assert_eq!(2, SNibble::P7.ilog2());
+    assert_eq!(0, SNibble::P7.ilog10());
+    assert_eq!(1, Period::P12.ilog10());
+    assert_eq!(2, Period::P9.ilog(3));
+    assert_eq!(63, u64::MAX.ilog(2));
+}
+
+#[test]
+#[should_panic(expected = "argument of integer logarithm must be positive")]
+fn ilog2_negative() {
+    let _ = SNibble::N1.ilog2();
+}
+
+#[test]
+#[should_panic(expected = "base of integer logarithm must be at least 2")]
+fn ilog_base_one() {
+    let _ = SNibble::P4.ilog(1);
+}
+
+#[test]
+fn gcd() {
+    assert_eq!(2, SNibble::N4.gcd(SNibble::P6));
+    assert_eq!(7, SNibble::Z0.gcd(SNibble::N7));
+    assert_eq!(0, SNibble::Z0.gcd(SNibble::Z0));
+    assert_eq!(3, Period::P6.gcd(Period::P9));
+    assert_eq!(8, SNibble::N8.gcd(SNibble::N8));
+}
+
+#[test]
+#[should_panic(expected = "greatest common divisor overflowed")]
+fn gcd_overflow() {
+    let _ = i8::MIN.gcd(i8::MIN);
+}
+
+#[test]
+fn checked_lcm() {
+    assert_eq!(Some(Period::P12), Period::P4.checked_lcm(Period::P6));
+    assert_eq!(Some(Period::P10), Period::P5.checked_lcm(Period::P10));
+    assert_eq!(None, Period::P4.checked_lcm(Period::P5));
+    assert_eq!(Some(SNibble::P6), SNibble::N2.checked_lcm(SNibble::P3));
+    assert_eq!(Some(SNibble::Z0), SNibble::Z0.checked_lcm(SNibble::P3));
+    assert_eq!(None, SNibble::N8.checked_lcm(SNibble::N8));
+    assert_eq!(None, u64::MAX.checked_lcm(u64::MAX - 1));
+    assert_eq!(Some(SNibble::MAX), SNibble::P7.checked_lcm(SNibble::P1));
+}
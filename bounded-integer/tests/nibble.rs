@@ -1,7 +1,10 @@
+#![allow(clippy::op_ref)]
+
 #[macro_use]
 extern crate bounded_integer;
 
-use bounded_integer::BoundedInteger;
+use bounded_integer::{Bound, Operation};
+use bounded_integer::prelude::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[allow(dead_code)]
@@ -26,6 +29,31 @@ fn from_repr() {
     assert_eq!(Some(SNibble::P7), SNibble::from_repr(7i8));
 }
 
+#[test]
+fn from_repr_unchecked() {
+    unsafe {
+        assert_eq!(SNibble::Z0, SNibble::from_repr_unchecked(0i8));
+        assert_eq!(SNibble::N8, SNibble::from_repr_unchecked(-8i8));
+        assert_eq!(NZUNibble::P15, NZUNibble::from_repr_unchecked(15u8));
+    }
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn from_repr_unchecked_out_of_range() {
+    let _ = unsafe { SNibble::from_repr_unchecked(8i8) };
+}
+
+#[test]
+fn bounded() {
+    const P5: SNibble = bounded!(SNibble, 5);
+    assert_eq!(SNibble::P5, P5);
+    assert_eq!(SNibble::N8, bounded!(SNibble, -8));
+    assert_eq!(NZUNibble::P15, bounded!(NZUNibble, 15));
+    assert!(bounded!(bool, 1));
+}
+
 #[test]
 fn to_repr() {
     assert_eq!(0i8, SNibble::Z0.to_repr());
@@ -43,6 +71,27 @@ fn max_value() {
     assert_eq!(SNibble::P7, SNibble::max_value());
 }
 
+#[test]
+fn consts() {
+    assert_eq!(SNibble::N8, SNibble::MIN);
+    assert_eq!(SNibble::P7, SNibble::MAX);
+    assert_eq!(-8i8, SNibble::MIN_REPR);
+    assert_eq!(7i8, SNibble::MAX_REPR);
+    assert_eq!(16, SNibble::COUNT);
+    assert_eq!(15, NZUNibble::COUNT);
+}
+
+#[test]
+fn consts_in_const_context() {
+    const TABLE: [u32; SNibble::COUNT] = [0; SNibble::COUNT];
+    assert_eq!(16, TABLE.len());
+
+    match SNibble::P7 {
+        SNibble::MAX => (),
+        _ => panic!(),
+    }
+}
+
 #[test]
 fn checked_add() {
     assert_eq!(Some(SNibble::P3), SNibble::P1.checked_add(SNibble::P2));
@@ -138,6 +187,76 @@ fn checked_rem_repr() {
     assert_eq!(None, NZUNibble::P2.checked_rem_repr(2));
 }
 
+#[test]
+fn try_add() {
+    assert_eq!(Ok(SNibble::P3), SNibble::P1.try_add(SNibble::P2));
+
+    let err = SNibble::P7.try_add(SNibble::P1).unwrap_err();
+    assert_eq!(Operation::Add, err.operation());
+    assert_eq!(7, err.lhs());
+    assert_eq!(Some(1), err.rhs());
+    assert_eq!(Some(8), err.result());
+    assert_eq!(Some(Bound::Max(7)), err.bound());
+    assert_eq!("7 + 1 = 8 is above maximum 7", err.to_string());
+}
+
+#[test]
+fn try_sub() {
+    assert_eq!(Ok(SNibble::P1), SNibble::P3.try_sub(SNibble::P2));
+
+    let err = SNibble::N8.try_sub(SNibble::P1).unwrap_err();
+    assert_eq!(Some(-9), err.result());
+    assert_eq!(Some(Bound::Min(-8)), err.bound());
+    assert_eq!("-8 - 1 = -9 is below minimum -8", err.to_string());
+}
+
+#[test]
+fn try_mul() {
+    assert_eq!(Ok(SNibble::N6), SNibble::N2.try_mul(SNibble::P3));
+    assert_eq!(Some(Bound::Min(-8)), SNibble::N3.try_mul(SNibble::P3).unwrap_err().bound());
+}
+
+#[test]
+fn try_div() {
+    assert_eq!(Ok(SNibble::P2), SNibble::P6.try_div(SNibble::P3));
+
+    let err = SNibble::P6.try_div(SNibble::Z0).unwrap_err();
+    assert!(err.is_division_by_zero());
+    assert_eq!(None, err.result());
+    assert_eq!(None, err.bound());
+    assert_eq!("6 / 0 divides by zero", err.to_string());
+
+    let err = NZUNibble::P1.try_div(NZUNibble::P2).unwrap_err();
+    assert_eq!(Some(0), err.result());
+    assert_eq!(Some(Bound::Min(1)), err.bound());
+}
+
+#[test]
+fn try_rem() {
+    assert_eq!(Ok(SNibble::N1), SNibble::N3.try_rem(SNibble::P2));
+    assert!(SNibble::N3.try_rem(SNibble::Z0).unwrap_err().is_division_by_zero());
+    assert_eq!(Some(0), NZUNibble::P2.try_rem(NZUNibble::P2).unwrap_err().result());
+}
+
+#[test]
+fn try_neg() {
+    assert_eq!(Ok(SNibble::N3), SNibble::P3.try_neg());
+
+    let err = SNibble::N8.try_neg().unwrap_err();
+    assert_eq!(Operation::Neg, err.operation());
+    assert_eq!(None, err.rhs());
+    assert_eq!("-(-8) = 8 is above maximum 7", err.to_string());
+}
+
+#[test]
+fn try_repr() {
+    assert_eq!(Ok(SNibble::P3), SNibble::P1.try_add_repr(2));
+    assert_eq!(Some(-100), SNibble::P1.try_sub_repr(101).unwrap_err().result());
+    assert_eq!(Some(Bound::Max(7)), SNibble::N8.try_mul_repr(-1).unwrap_err().bound());
+    assert_eq!(Ok(SNibble::N2), SNibble::N6.try_div_repr(3));
+    assert_eq!(Ok(SNibble::P1), SNibble::P3.try_rem_repr(2));
+}
+
 #[test]
 fn saturating_add() {
     assert_eq!(SNibble::P7, SNibble::P4.saturating_add(SNibble::P4));
@@ -178,6 +297,85 @@ fn saturating_mul_repr() {
     assert_eq!(SNibble::N8, SNibble::P5.saturating_mul_repr(-2));
 }
 
+#[test]
+fn wrapping_add() {
+    assert_eq!(SNibble::P3, SNibble::P1.wrapping_add(SNibble::P2));
+    assert_eq!(SNibble::N8, SNibble::P7.wrapping_add(SNibble::P1));
+    assert_eq!(SNibble::P7, SNibble::N8.wrapping_add(SNibble::N1));
+    assert_eq!(NZUNibble::P1, NZUNibble::P15.wrapping_add(NZUNibble::P1));
+}
+
+#[test]
+fn wrapping_sub() {
+    assert_eq!(SNibble::P1, SNibble::P3.wrapping_sub(SNibble::P2));
+    assert_eq!(SNibble::P7, SNibble::N8.wrapping_sub(SNibble::P1));
+    assert_eq!(NZUNibble::P15, NZUNibble::P1.wrapping_sub(NZUNibble::P1));
+}
+
+#[test]
+fn wrapping_mul() {
+    assert_eq!(SNibble::P6, SNibble::P2.wrapping_mul(SNibble::P3));
+    assert_eq!(SNibble::N8, SNibble::P2.wrapping_mul(SNibble::P4));
+    assert_eq!(SNibble::P1, SNibble::N3.wrapping_mul(SNibble::P5));
+}
+
+#[test]
+fn wrapping_neg() {
+    assert_eq!(SNibble::N3, SNibble::P3.wrapping_neg());
+    assert_eq!(SNibble::N8, SNibble::N8.wrapping_neg());
+    assert_eq!(NZUNibble::P12, NZUNibble::P3.wrapping_neg());
+}
+
+#[test]
+fn wrapping_add_repr() {
+    assert_eq!(SNibble::N8, SNibble::P7.wrapping_add_repr(1));
+    assert_eq!(SNibble::N6, SNibble::P7.wrapping_add_repr(35));
+    assert_eq!(SNibble::P7, SNibble::N8.wrapping_add_repr(-1));
+}
+
+#[test]
+fn wrapping_sub_repr() {
+    assert_eq!(SNibble::P7, SNibble::N8.wrapping_sub_repr(1));
+    assert_eq!(SNibble::N8, SNibble::P7.wrapping_sub_repr(-1));
+}
+
+#[test]
+fn wrapping_mul_repr() {
+    assert_eq!(SNibble::N8, SNibble::P4.wrapping_mul_repr(2));
+    assert_eq!(SNibble::P4, SNibble::P4.wrapping_mul_repr(-127));
+}
+
+#[test]
+fn unchecked_add() {
+    unsafe {
+        assert_eq!(SNibble::P3, SNibble::P1.unchecked_add(SNibble::P2));
+        assert_eq!(SNibble::N1, SNibble::P1.unchecked_add_repr(-2));
+    }
+}
+
+#[test]
+fn unchecked_sub() {
+    unsafe {
+        assert_eq!(SNibble::P1, SNibble::P3.unchecked_sub(SNibble::P2));
+        assert_eq!(NZUNibble::P1, NZUNibble::P3.unchecked_sub_repr(2));
+    }
+}
+
+#[test]
+fn unchecked_mul() {
+    unsafe {
+        assert_eq!(SNibble::N6, SNibble::N2.unchecked_mul(SNibble::P3));
+        assert_eq!(SNibble::P6, SNibble::N2.unchecked_mul_repr(-3));
+    }
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn unchecked_add_overflow() {
+    let _ = unsafe { SNibble::P7.unchecked_add(SNibble::P1) };
+}
+
 #[test]
 fn into_repr() {
     assert_eq!(0i8, SNibble::Z0.into());
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::CheckedArith;
+use bounded_integer::overflow::{DebugPanic, OverflowPolicy, Saturate, Wrap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum Saturating {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(Saturating, i8, Saturating::N8, Saturating::P7, Saturate);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum Wrapping {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(Wrapping, i8, Wrapping::N8, Wrapping::P7, Wrap);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum NonZero {
+    P1 = 1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15
+}
+bounded_integer_impl!(NonZero, u8, NonZero::P1, NonZero::P15);
+bounded_integer_add_self_impls!(NonZero, Wrap);
+bounded_integer_div_repr_impls!(NonZero, Saturate);
+bounded_integer_mul_repr_impls!(NonZero, DebugPanic);
+
+#[test]
+fn saturate() {
+    assert_eq!(Saturating::P3, Saturating::P1 + Saturating::P2);
+    assert_eq!(Saturating::P7, Saturating::P7 + Saturating::P1);
+    assert_eq!(Saturating::N8, Saturating::N8 - 1);
+    assert_eq!(Saturating::N8, Saturating::N3 * Saturating::P3);
+    assert_eq!(Saturating::P7, -Saturating::N8);
+    assert_eq!(NonZero::P1, NonZero::P1 / 2);
+}
+
+#[test]
+#[should_panic]
+fn saturate_div_by_zero() {
+    let _ = Saturating::P1 / Saturating::Z0;
+}
+
+#[test]
+fn wrap() {
+    assert_eq!(Wrapping::P3, Wrapping::P1 + Wrapping::P2);
+    assert_eq!(Wrapping::N8, Wrapping::P7 + Wrapping::P1);
+    assert_eq!(Wrapping::P7, Wrapping::N8 - 1);
+    assert_eq!(Wrapping::P1, Wrapping::N3 * Wrapping::P5);
+    assert_eq!(Wrapping::N8, -Wrapping::N8);
+    assert_eq!(NonZero::P1, NonZero::P15 + NonZero::P1);
+}
+
+#[test]
+#[should_panic]
+fn wrap_rem_by_zero() {
+    let _ = Wrapping::P1 % 0;
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn debug_panic() {
+    assert_eq!(NonZero::P1, NonZero::P8 * 2);
+}
+
+#[test]
+fn debug_panic_release() {
+    let result = NonZero::P8.try_mul_repr(2);
+    assert_eq!(NonZero::P1, DebugPanic::resolve_debug(result, false));
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflowed")]
+fn debug_panic_debug() {
+    let result = NonZero::P8.try_mul_repr(2);
+    let _: NonZero = DebugPanic::resolve_debug(result, true);
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::{Bounded, BoundedInteger, CheckedArith, SaturatingArith};
+
+fn span<T: BoundedInteger>() -> Option<T::Repr> {
+    use bounded_integer::Repr;
+    T::max_value().to_repr().checked_sub(T::min_value().to_repr())
+}
+
+#[test]
+fn from_repr() {
+    assert_eq!(Some(5u8), <u8 as Bounded>::from_repr(5));
+    assert_eq!(Some(-5i64), <i64 as Bounded>::from_repr(-5));
+}
+
+#[test]
+fn min_max_value() {
+    assert_eq!(0u8, <u8 as Bounded>::min_value());
+    assert_eq!(255u8, <u8 as Bounded>::max_value());
+    assert_eq!(-128i8, <i8 as Bounded>::min_value());
+    assert_eq!(127i8, <i8 as Bounded>::max_value());
+}
+
+#[test]
+fn consts() {
+    assert_eq!(256, <u8 as Bounded>::COUNT);
+    assert_eq!(65536, <i16 as Bounded>::COUNT);
+    assert_eq!(usize::MAX, <u64 as Bounded>::COUNT);
+    assert_eq!(2, <bool as Bounded>::COUNT);
+    assert_eq!(1u8, <bool as Bounded>::MAX_REPR);
+}
+
+#[test]
+fn generic() {
+    assert_eq!(Some(255), span::<u8>());
+    assert_eq!(None, span::<i8>());
+    assert_eq!(Some(1), span::<bool>());
+}
+
+#[test]
+fn checked_add() {
+    assert_eq!(Some(3u8), CheckedArith::checked_add(1u8, 2));
+    assert_eq!(None, CheckedArith::checked_add(255u8, 1));
+}
+
+#[test]
+fn saturating_sub() {
+    assert_eq!(-128i8, SaturatingArith::saturating_sub(-100i8, 100));
+}
+
+#[test]
+fn wrapping_mul() {
+    use bounded_integer::WrappingArith;
+    assert_eq!(1u64, WrappingArith::wrapping_mul(u64::MAX, u64::MAX));
+    assert_eq!(0i64, WrappingArith::wrapping_mul(i64::MIN, 2));
+    assert_eq!(1u8, WrappingArith::wrapping_add(255u8, 2));
+}
+
+#[test]
+fn try_mul() {
+    let err = CheckedArith::try_mul(u64::MAX, u64::MAX).unwrap_err();
+    assert_eq!(None, err.result());
+    assert_eq!(Some(bounded_integer::Bound::Max(u64::MAX as i128)), err.bound());
+}
+
+#[test]
+fn bounded() {
+    const MAX: u8 = bounded!(u8, 255);
+    assert_eq!(255, MAX);
+}
+
+#[test]
+fn bool_from_repr() {
+    assert_eq!(Some(false), bool::from_repr(0));
+    assert_eq!(Some(true), bool::from_repr(1));
+    assert_eq!(None, bool::from_repr(2));
+}
+
+#[test]
+fn bool_checked_add() {
+    assert_eq!(Some(true), false.checked_add(true));
+    assert_eq!(None, true.checked_add(true));
+}
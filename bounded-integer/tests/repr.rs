@@ -0,0 +1,64 @@
+extern crate bounded_integer;
+
+use bounded_integer::Repr;
+
+fn sum<R: Repr>(values: &[R]) -> Option<R> {
+    values.iter().try_fold(R::ZERO, |acc, &v| acc.checked_add(v))
+}
+
+fn is_signed<R: Repr>() -> bool {
+    R::IS_SIGNED
+}
+
+#[test]
+fn constants() {
+    assert_eq!(0u8, <u8 as Repr>::ZERO);
+    assert_eq!(1i16, <i16 as Repr>::ONE);
+    assert_eq!(-128i8, <i8 as Repr>::MIN);
+    assert_eq!(255u8, <u8 as Repr>::MAX);
+    assert_eq!(32, <u32 as Repr>::BITS);
+    assert!(is_signed::<i64>());
+    assert!(!is_signed::<u64>());
+}
+
+#[test]
+fn generic_sum() {
+    assert_eq!(Some(6u8), sum(&[1u8, 2, 3]));
+    assert_eq!(None, sum(&[200u8, 100]));
+}
+
+#[test]
+fn to_i128() {
+    assert_eq!(-128, Repr::to_i128(-128i8));
+    assert_eq!(18446744073709551615, Repr::to_i128(u64::MAX));
+}
+
+#[test]
+fn to_u128() {
+    assert_eq!(Some(5), Repr::to_u128(5i8));
+    assert_eq!(None, Repr::to_u128(-5i8));
+    assert_eq!(Some(18446744073709551615), Repr::to_u128(u64::MAX));
+}
+
+#[test]
+fn from_i128() {
+    assert_eq!(Some(-128i8), Repr::from_i128(-128));
+    assert_eq!(None, <i8 as Repr>::from_i128(128));
+    assert_eq!(None, <u8 as Repr>::from_i128(-1));
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(-128i8, Repr::wrapping_add(127i8, 1));
+    assert_eq!(255u8, Repr::wrapping_sub(0u8, 1));
+    assert_eq!(-128i8, Repr::wrapping_neg(-128i8));
+    assert_eq!(-128i8, Repr::wrapping_div(-128i8, -1));
+}
+
+#[test]
+fn overflowing() {
+    assert_eq!((-128i8, true), Repr::overflowing_add(127i8, 1));
+    assert_eq!((2u8, false), Repr::overflowing_sub(3u8, 1));
+    assert_eq!((0u8, true), Repr::overflowing_mul(128u8, 2));
+    assert_eq!((0i8, true), Repr::overflowing_rem(-128i8, -1));
+}
@@ -0,0 +1,106 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::slice;
+use bounded_integer::{Contiguous, WrappingArith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+fn nibbles(reprs: &[i8]) -> Vec<SNibble> {
+    SNibble::from_repr_slice(reprs).unwrap().to_vec()
+}
+
+fn reprs(values: &[SNibble]) -> Vec<i8> {
+    SNibble::as_repr_slice(values).to_vec()
+}
+
+#[test]
+fn checked_add_slices() {
+    let mut out = nibbles(&[0, 0, 0]);
+    let result = slice::checked_add_slices(&nibbles(&[1, -8, 3]), &nibbles(&[2, 7, 4]), &mut out);
+    assert_eq!(Ok(()), result);
+    assert_eq!(vec![3, -1, 7], reprs(&out));
+
+    let result = slice::checked_add_slices(&nibbles(&[1, 4, 6]), &nibbles(&[2, 4, 6]), &mut out);
+    assert_eq!(Err(1), result);
+}
+
+#[test]
+fn checked_sub_slices() {
+    let mut out = nibbles(&[0, 0]);
+    assert_eq!(Ok(()), slice::checked_sub_slices(&nibbles(&[1, -3]), &nibbles(&[2, 5]), &mut out));
+    assert_eq!(vec![-1, -8], reprs(&out));
+    assert_eq!(Err(0), slice::checked_sub_slices(&nibbles(&[-8, 0]), &nibbles(&[1, 0]), &mut out));
+}
+
+#[test]
+fn checked_mul_slices() {
+    let mut out = [0u8; 3];
+    assert_eq!(Ok(()), slice::checked_mul_slices(&[2u8, 3, 15], &[100, 50, 17], &mut out));
+    assert_eq!([200, 150, 255], out);
+    assert_eq!(Err(2), slice::checked_mul_slices(&[2u8, 3, 16], &[100, 50, 16], &mut out));
+}
+
+#[test]
+fn checked_add_slices_later_chunk() {
+    let lhs = vec![SNibble::P3; 300];
+    let mut rhs = vec![SNibble::P1; 300];
+    rhs[200] = SNibble::P5;
+    let mut out = vec![SNibble::Z0; 300];
+    assert_eq!(Err(200), slice::checked_add_slices(&lhs, &rhs, &mut out));
+    assert!(out[..192].iter().all(|&value| value == SNibble::P4));
+    assert!(out[192..].iter().all(|&value| value == SNibble::Z0));
+}
+
+#[test]
+#[should_panic(expected = "slice lengths differ")]
+fn checked_add_slices_lengths() {
+    let mut out = [0u8; 2];
+    let _ = slice::checked_add_slices(&[1u8, 2], &[1], &mut out);
+}
+
+#[test]
+fn saturating_assign_slice() {
+    let mut values = nibbles(&[-8, 0, 5, 7]);
+    slice::saturating_add_assign_slice(&mut values, 3);
+    assert_eq!(vec![-5, 3, 7, 7], reprs(&values));
+    slice::saturating_sub_assign_slice(&mut values, 10);
+    assert_eq!(vec![-8, -7, -3, -3], reprs(&values));
+    slice::saturating_mul_assign_slice(&mut values, -2);
+    assert_eq!(vec![7, 7, 6, 6], reprs(&values));
+    slice::saturating_add_assign_slice(&mut values, i8::MIN);
+    assert_eq!(vec![-8, -8, -8, -8], reprs(&values));
+}
+
+#[test]
+fn wrapping_assign_slice() {
+    let all = nibbles(&(-8..8).collect::<Vec<i8>>());
+    for &rhs in &[-100i8, -9, -1, 0, 3, 15, 100] {
+        let mut values = all.clone();
+        slice::wrapping_add_assign_slice(&mut values, rhs);
+        assert!(values.iter().zip(&all).all(|(&v, &a)| v == a.wrapping_add_repr(rhs)));
+
+        let mut values = all.clone();
+        slice::wrapping_sub_assign_slice(&mut values, rhs);
+        assert!(values.iter().zip(&all).all(|(&v, &a)| v == a.wrapping_sub_repr(rhs)));
+
+        let mut values = all.clone();
+        slice::wrapping_mul_assign_slice(&mut values, rhs);
+        assert!(values.iter().zip(&all).all(|(&v, &a)| v == a.wrapping_mul_repr(rhs)));
+    }
+}
+
+#[test]
+fn wrapping_assign_slice_full_range() {
+    let mut values = [250u8, 5, 0];
+    slice::wrapping_add_assign_slice(&mut values, 10);
+    assert_eq!([4, 15, 10], values);
+    slice::wrapping_mul_assign_slice(&mut values, 100);
+    assert_eq!([144, 220, 232], values);
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::Bound;
+use bounded_integer::overflow::Saturate;
+use bounded_integer::prelude::*;
+
+/// Odd values from 1 to 5, which is not contiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+enum Odd { P1 = 1, P3 = 3, P5 = 5 }
+
+impl Bounded for Odd {
+    type Repr = u8;
+    const MIN: Self = Odd::P1;
+    const MAX: Self = Odd::P5;
+    const MIN_REPR: u8 = 1;
+    const MAX_REPR: u8 = 5;
+    const COUNT: usize = 3;
+
+    fn from_repr(repr: u8) -> Option<Self> {
+        match repr {
+            1 => Some(Odd::P1),
+            3 => Some(Odd::P3),
+            5 => Some(Odd::P5),
+            _ => None,
+        }
+    }
+
+    fn to_repr(self) -> u8 { self as u8 }
+}
+
+bounded_integer_add_repr_impls!(Odd, Saturate);
+
+#[test]
+fn checked() {
+    assert_eq!(Some(Odd::P3), Odd::P1.checked_add_repr(2));
+    assert_eq!(None, Odd::P1.checked_add_repr(1));
+    assert_eq!(None, Odd::P1.checked_add(Odd::P5));
+    assert_eq!(None, Odd::P3.checked_mul(Odd::P3));
+}
+
+#[test]
+fn try_gap() {
+    let err = Odd::P1.try_add_repr(1).unwrap_err();
+    assert!(err.is_gap());
+    assert_eq!(None, err.bound());
+    assert_eq!("1 + 1 = 2 is not a valid value", err.to_string());
+
+    assert_eq!(Some(Bound::Max(5)), Odd::P5.try_add_repr(2).unwrap_err().bound());
+    assert!(!Odd::P5.try_add_repr(2).unwrap_err().is_gap());
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(Odd::P5, Odd::P5.saturating_add(Odd::P5));
+    assert_eq!(Odd::P1, Odd::P1.saturating_sub(Odd::P5));
+}
+
+#[test]
+fn saturate_policy() {
+    assert_eq!(Odd::P5, Odd::P3 + 2);
+    assert_eq!(Odd::P5, Odd::P5 + 4);
+}
+
+#[test]
+#[should_panic(expected = "1 + 1 = 2 is not a valid value")]
+fn saturate_policy_gap() {
+    let _ = Odd::P1 + 1;
+}
+
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate bounded_integer;
+
+use bounded_integer::{Checked, Saturating, Wrapping};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+#[repr(i8)]
+enum SNibble {
+    N8 = -8, N7, N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6, P7
+}
+bounded_integer_impls!(SNibble, i8, SNibble::N8, SNibble::P7);
+
+#[test]
+fn checked() {
+    assert_eq!(Checked(Some(SNibble::P3)), Checked(Some(SNibble::P1)) + Checked(Some(SNibble::P2)));
+    assert_eq!(Checked(Some(SNibble::N1)), Checked(Some(SNibble::P1)) - Checked(Some(SNibble::P2)));
+    assert_eq!(Checked(Some(SNibble::P6)), Checked(Some(SNibble::P2)) * Checked(Some(SNibble::P3)));
+    assert_eq!(Checked(Some(SNibble::P2)), Checked(Some(SNibble::P6)) / Checked(Some(SNibble::P3)));
+    assert_eq!(Checked(Some(SNibble::P1)), Checked(Some(SNibble::P3)) % Checked(Some(SNibble::P2)));
+    assert_eq!(Checked(Some(SNibble::N3)), -Checked(Some(SNibble::P3)));
+}
+
+#[test]
+fn checked_overflow() {
+    assert_eq!(Checked(None), Checked(Some(SNibble::P7)) + Checked(Some(SNibble::P1)));
+    assert_eq!(Checked(None), -Checked(Some(SNibble::N8)));
+    assert_eq!(Checked(None), Checked(Some(SNibble::P1)) / Checked(Some(SNibble::Z0)));
+}
+
+#[test]
+fn checked_propagates() {
+    let x = Checked::from(SNibble::P4);
+    assert_eq!(Checked(None), x + x - x);
+    assert_eq!(Checked(None), Checked(None) * x);
+
+    let mut y = x;
+    y += x;
+    y -= x;
+    assert_eq!(Checked(None), y);
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(Saturating(SNibble::P7), Saturating(SNibble::P4) + Saturating(SNibble::P4));
+    assert_eq!(Saturating(SNibble::N8), Saturating(SNibble::N4) - Saturating(SNibble::P5));
+    assert_eq!(Saturating(SNibble::N8), Saturating(SNibble::N2) * Saturating(SNibble::P5));
+
+    let mut x = Saturating(SNibble::P7);
+    x += Saturating(SNibble::P1);
+    x -= Saturating(SNibble::P1);
+    assert_eq!(Saturating(SNibble::P6), x);
+}
+
+#[test]
+fn saturating_div_rem_neg() {
+    assert_eq!(Saturating(SNibble::P2), Saturating(SNibble::P6) / Saturating(SNibble::P3));
+    assert_eq!(Saturating(SNibble::P7), Saturating(SNibble::N8) / Saturating(SNibble::N1));
+    assert_eq!(Saturating(SNibble::P1), Saturating(SNibble::P3) % Saturating(SNibble::P2));
+    assert_eq!(Saturating(SNibble::N3), -Saturating(SNibble::P3));
+    assert_eq!(Saturating(SNibble::P7), -Saturating(SNibble::N8));
+
+    let mut x = Saturating::from(SNibble::P7);
+    x /= Saturating(SNibble::P2);
+    x %= Saturating(SNibble::P2);
+    assert_eq!(Saturating(SNibble::P1), x);
+}
+
+#[test]
+#[should_panic(expected = "divides by zero")]
+fn saturating_div_by_zero() {
+    let _ = Saturating(SNibble::P1) / Saturating(SNibble::Z0);
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(Wrapping(SNibble::N8), Wrapping(SNibble::P7) + Wrapping(SNibble::P1));
+    assert_eq!(Wrapping(SNibble::P7), Wrapping(SNibble::N8) - Wrapping(SNibble::P1));
+    assert_eq!(Wrapping(SNibble::N8), Wrapping(SNibble::P2) * Wrapping(SNibble::P4));
+    assert_eq!(Wrapping(SNibble::N8), -Wrapping(SNibble::N8));
+
+    let mut x = Wrapping(SNibble::P7);
+    x += Wrapping(SNibble::P1);
+    assert_eq!(Wrapping(SNibble::N8), x);
+}
+
+#[test]
+fn wrapping_div_rem() {
+    assert_eq!(Wrapping(SNibble::P2), Wrapping(SNibble::P6) / Wrapping(SNibble::P3));
+    assert_eq!(Wrapping(SNibble::N8), Wrapping(SNibble::N8) / Wrapping(SNibble::N1));
+    assert_eq!(Wrapping(SNibble::N1), Wrapping(SNibble::N3) % Wrapping(SNibble::P2));
+
+    let mut x = Wrapping::from(SNibble::P7);
+    x /= Wrapping(SNibble::P2);
+    x %= Wrapping(SNibble::P2);
+    assert_eq!(Wrapping(SNibble::P1), x);
+}
+
+#[test]
+#[should_panic(expected = "divides by zero")]
+fn wrapping_rem_by_zero() {
+    let _ = Wrapping(SNibble::P1) % Wrapping(SNibble::Z0);
+}